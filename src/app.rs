@@ -1,100 +1,719 @@
-use std::{error::Error, io, time::Duration};
+use std::{error::Error, io, path::PathBuf, time::Duration};
 
 use crate::{
-    input::{poll_event, HandleEvent, InputContext, InputEvent, ScreenInfo},
-    model::{Clock, GameModel},
-    ui::{Camera, Screen},
+    config::GameConfig,
+    input::{poll_event, HandleEvent, InputContext, InputEvent, Keymap, ScreenInfo},
+    menu::{GameSetup, MainMenuItem, Menu, MenuInput, PauseMenuItem, Settings, SetupAction},
+    model::{Clock, FixedTimestep, GameEvent, GameModel, Point, SIMULATION_STEP},
+    replay::{Replay, ReplayEntry, ReplayPlayer},
+    ui::{Camera, Dimmed, Drawable, HelpOverlay, PausePanel, ReplayStatus, Screen, Toasts},
 };
 
 #[derive(Debug, PartialEq, Eq)]
 enum AppState {
     NotStarted,
+    MainMenu,
+    NewGame,
+    Settings,
+    PauseMenu,
+    /// Paused with the menu closed, the player can still look around and plan
     Paused,
     Running,
+    Summary,
     Closing,
     Closed,
 }
 
+/// Replay of the last game started from the menu
+struct Recording {
+    replay: Option<Replay>,
+    content: Option<PathBuf>,
+    content_hash: Option<u64>,
+    path: PathBuf,
+    /// File the current replay is written to, picked when the game starts
+    replay_path: PathBuf,
+}
+
+/// Creates, loads and saves games on behalf of the menus
+pub trait Launcher<G> {
+    fn new_game(&mut self, config: GameConfig) -> Result<G, Box<dyn Error>>;
+    fn load_game(&mut self) -> Result<G, Box<dyn Error>>;
+    fn save_game(&self, game: &G) -> Result<(), Box<dyn Error>>;
+}
+
+struct Playback<G> {
+    player: ReplayPlayer,
+    make_model: Box<dyn Fn() -> Result<G, Box<dyn Error>>>,
+}
+
 pub struct App<G: GameModel + HandleEvent> {
     game_model: G,
     input_context: InputContext,
+    keymap: Keymap,
     screen: Screen,
     camera: Camera,
     update_clock: Clock,
+    timestep: FixedTimestep,
+    tick: u64,
+    tick_duration: Duration,
+    recording: Option<Recording>,
+    playback: Option<Playback<G>>,
+    launcher: Option<Box<dyn Launcher<G>>>,
+    /// Setup of the current game, unknown for loaded games
+    config: Option<GameConfig>,
+    main_menu: Menu<MainMenuItem>,
+    pause_menu: Menu<PauseMenuItem>,
+    setup: Option<GameSetup>,
+    settings: Option<Settings>,
+    build_phase: bool,
+    toasts: Toasts,
     state: AppState,
 }
 
 impl<G: GameModel + HandleEvent> App<G> {
+    const MAX_CATCH_UP_STEPS: u32 = 30;
+    /// Ten seconds of simulation
+    const SEEK_TICKS: u64 =
+        (Duration::from_secs(10).as_nanos() / SIMULATION_STEP.as_nanos()) as u64;
+
     pub fn new(model: G, ui: Screen, camera: Camera) -> Self {
         Self {
-            input_context: InputContext::new(model.min_tower_gap()),
+            input_context: InputContext::new(model.min_tower_gap(), model.content().towers),
             game_model: model,
+            keymap: Keymap::default(),
             screen: ui,
-            camera: camera,
+            camera,
             update_clock: Clock::from_now(),
+            timestep: FixedTimestep::new(SIMULATION_STEP, Self::MAX_CATCH_UP_STEPS),
+            tick: 0,
+            tick_duration: Duration::ZERO,
+            recording: None,
+            playback: None,
+            launcher: None,
+            config: None,
+            main_menu: Menu::new("Tower Defence", &MainMenuItem::ALL),
+            pause_menu: Menu::new("PAUSED", &PauseMenuItem::WITHOUT_RESTART),
+            setup: None,
+            settings: None,
+            build_phase: false,
+            toasts: Toasts::new(),
             state: AppState::NotStarted,
         }
     }
 
+    pub fn replay(
+        player: ReplayPlayer,
+        make_model: impl Fn() -> Result<G, Box<dyn Error>> + 'static,
+        ui: Screen,
+        camera: Camera,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut app = Self::new(make_model()?, ui, camera);
+        app.playback = Some(Playback {
+            player,
+            make_model: Box::new(make_model),
+        });
+        Ok(app)
+    }
+
+    /// Every game started from the menu is recorded into its own file, named
+    /// after `path` and numbered when the name is taken
+    pub fn set_recording(
+        &mut self,
+        content: Option<PathBuf>,
+        content_hash: Option<u64>,
+        path: PathBuf,
+    ) -> &mut Self {
+        self.recording = Some(Recording {
+            replay: None,
+            content,
+            content_hash,
+            replay_path: path.clone(),
+            path,
+        });
+        self
+    }
+
+    pub fn set_keymap(&mut self, keymap: Keymap) -> &mut Self {
+        self.keymap = keymap;
+        self
+    }
+
+    pub fn set_launcher(&mut self, launcher: impl Launcher<G> + 'static) -> &mut Self {
+        self.launcher = Some(Box::new(launcher));
+        self
+    }
+
+    /// Allows changing towers while paused
+    pub fn set_build_phase(&mut self, build_phase: bool) -> &mut Self {
+        self.build_phase = build_phase;
+        self
+    }
+
+    /// Defaults of the new game screen
+    pub fn set_new_game_config(&mut self, config: GameConfig) -> &mut Self {
+        self.setup = Some(GameSetup::new(config));
+        self
+    }
+
+    /// Starts in the main menu instead of the given game, which is shown behind it
+    pub fn open_menu(&mut self) -> &mut Self {
+        self.state = AppState::MainMenu;
+        self
+    }
+
     pub fn run(&mut self, tick_duration: Duration) -> io::Result<()> {
-        self.state = AppState::Running;
+        self.tick_duration = tick_duration;
+        if self.state == AppState::NotStarted {
+            self.state = AppState::Running;
+        }
         self.screen.init()?;
-        let run_res = self.run_impl(tick_duration);
+        let run_res = self.run_impl();
         self.screen.kill()?;
+        // The error which stopped the game matters more than a failed save
+        let save_res = self.save_recording();
         run_res?;
+        save_res?;
         self.state = AppState::Closed;
         Ok(())
     }
 
-    fn run_impl(&mut self, tick_duration: Duration) -> io::Result<()> {
+    fn run_impl(&mut self) -> io::Result<()> {
         while self.state != AppState::Closing {
-            while self.update_clock.elapsed() < tick_duration {
-                let timeout = tick_duration.saturating_sub(self.update_clock.elapsed());
+            while self.update_clock.elapsed() < self.tick_duration {
+                let timeout = self
+                    .tick_duration
+                    .saturating_sub(self.update_clock.elapsed());
 
-                let screen_info = ScreenInfo::from_frame_size(self.camera, self.screen.size()?);
-                self.input_context.set_screen_info(screen_info);
+                // During playback the recorded screen info is used instead
+                if self.playback.is_none() {
+                    let screen_info = ScreenInfo::from_frame_size(self.camera, self.screen.size()?);
+                    self.input_context.set_screen_info(screen_info);
+                }
 
-                let event = poll_event(timeout)?;
-                let _ = self.handle(event);
+                let event = poll_event(timeout, &self.keymap)?;
+                if let Err(err) = self.handle(event) {
+                    self.toasts.error(err.to_string());
+                }
             }
             self.update()?;
-            if self.game_model.is_over() {
-                self.state = AppState::Closing
+            if self.state == AppState::Running && self.game_model.is_over() {
+                self.state = AppState::Summary
             }
         }
         Ok(())
     }
 
     fn update(&mut self) -> io::Result<()> {
-        if self.state != AppState::Paused {
-            let delta_time = self.update_clock.elapsed();
-            self.game_model.update(delta_time);
-        }
-        self.screen
-            .draw_frame(&self.camera, &self.game_model, &self.input_context)?;
+        let elapsed = self.update_clock.elapsed();
         self.update_clock.tick();
-        Ok(())
+        self.toasts.update(elapsed);
+
+        if self.state == AppState::Summary {
+            return self.screen.draw_summary(&self.camera, &self.game_model);
+        }
+        if self.playback.is_some() {
+            return self.update_playback(elapsed);
+        }
+
+        if self.state == AppState::Running {
+            let steps = self.timestep.advance(elapsed);
+            for _ in 0..steps {
+                self.game_model.update(self.timestep.step());
+                self.tick += 1;
+            }
+        }
+        self.notify_events();
+        self.screen.set_paused(self.state == AppState::Paused);
+        match (&self.state, self.setup, self.settings) {
+            (AppState::MainMenu, _, _) => {
+                let menu = self.main_menu;
+                self.draw_with(&menu)
+            }
+            (AppState::PauseMenu, _, _) => {
+                let menu = self.pause_menu;
+                self.draw_with(&Dimmed::new(&menu))
+            }
+            (AppState::Paused, _, _) if !self.input_context.help_shown() => {
+                let panel = PausePanel::new(&self.keymap, self.build_phase);
+                self.screen.draw_frame_with(
+                    &self.camera,
+                    &self.game_model,
+                    &self.input_context,
+                    &self.keymap,
+                    &self.toasts,
+                    &Dimmed::new(&panel),
+                )
+            }
+            (AppState::NewGame, Some(setup), _) => self.draw_with(&setup),
+            (AppState::Settings, _, Some(settings)) => self.draw_with(&settings),
+            _ => self.screen.draw_frame(
+                &self.camera,
+                &self.game_model,
+                &self.input_context,
+                &self.keymap,
+                &self.toasts,
+            ),
+        }
+    }
+
+    fn draw_with(&mut self, overlay: &impl Drawable) -> io::Result<()> {
+        self.screen.draw_frame_with(
+            &self.camera,
+            &self.game_model,
+            &self.input_context,
+            &self.keymap,
+            &self.toasts,
+            overlay,
+        )
     }
 
     fn handle(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        if self.state == AppState::Summary {
+            return self.handle_summary(event);
+        }
+        if self.playback.is_some() {
+            return self.handle_playback(event);
+        }
+        match self.state {
+            AppState::MainMenu => return self.handle_main_menu(event),
+            AppState::NewGame => return self.handle_setup(event),
+            AppState::Settings => return self.handle_settings(event),
+            AppState::PauseMenu => return self.handle_pause_menu(event),
+            _ => {}
+        }
+
+        let paused = self.state == AppState::Paused;
+        if paused
+            && event == InputEvent::TowerDeselect
+            && self.game_model.selected_tower().is_none()
+        {
+            self.state = AppState::PauseMenu;
+            return Ok(());
+        }
+
         self.camera.handle(event, &self.input_context)?;
+        let screen_info = self.input_context.screen_info();
+        let cursor = self.input_context.cursor();
         self.input_context.handle(event)?;
+        self.clamp_help_scroll(event)?;
 
-        if self.state != AppState::Paused {
+        let affects_model = !paused || self.build_phase || !self.is_build_event(event);
+        self.record(event, screen_info, cursor, affects_model);
+        if affects_model {
             self.game_model.handle(event, &self.input_context)?;
+        } else {
+            self.toasts.warning("Building is disabled while paused");
         }
         match event {
-            InputEvent::GameQuit => self.state = AppState::Closing,
+            InputEvent::GameQuit => {
+                self.game_model.quit();
+                self.state = AppState::Summary
+            }
+            InputEvent::GameSave => self.save()?,
+            InputEvent::GamePauseSwitch if paused => self.resume(),
+            InputEvent::GamePauseSwitch => {
+                let items: &'static [PauseMenuItem] = match self.config {
+                    Some(_) => &PauseMenuItem::ALL,
+                    None => &PauseMenuItem::WITHOUT_RESTART,
+                };
+                self.pause_menu = Menu::new("PAUSED", items);
+                self.state = AppState::PauseMenu;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Events which build, sell or change towers, allowed while paused only
+    /// in the build phase. Pressing on an existing tower only selects it
+    fn is_build_event(&self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::MousePressedL(pos) => {
+                let position = pos.to_world_point(self.input_context.screen_info());
+                self.game_model.tower_at(position).is_none()
+            }
+            InputEvent::CursorPlace => self
+                .game_model
+                .tower_at(self.input_context.cursor())
+                .is_none(),
+            InputEvent::MousePressedR(_)
+            | InputEvent::CursorSell
+            | InputEvent::TowerUpgrade
+            | InputEvent::TowerTargetingNext => true,
+            _ => false,
+        }
+    }
+
+    /// Without a launcher there is no menu to return to
+    fn handle_summary(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        match event {
+            InputEvent::None
+            | InputEvent::Unknown
+            | InputEvent::Resize
+            | InputEvent::MouseMovedTo(_) => {}
+            _ if self.launcher.is_some() => self.state = AppState::MainMenu,
+            _ => self.state = AppState::Closing,
+        }
+        Ok(())
+    }
+
+    fn handle_main_menu(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        if event == InputEvent::GameQuit {
+            self.state = AppState::Closing;
+            return Ok(());
+        }
+        let Some(input) = MenuInput::from_event(event) else {
+            return Ok(());
+        };
+        match self.main_menu.handle(input) {
+            Some(MainMenuItem::NewGame) if self.setup.is_some() => self.state = AppState::NewGame,
+            Some(MainMenuItem::Load) => {
+                if let Some(launcher) = self.launcher.as_mut() {
+                    let model = launcher.load_game()?;
+                    self.start_game(model, None)?;
+                }
+            }
+            Some(MainMenuItem::Settings) => {
+                self.settings = Some(Settings::new(
+                    self.camera.scale(),
+                    self.tick_duration,
+                    self.build_phase,
+                ));
+                self.state = AppState::Settings;
+            }
+            Some(MainMenuItem::Quit) => self.state = AppState::Closing,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_setup(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        if event == InputEvent::GameQuit {
+            self.state = AppState::Closing;
+            return Ok(());
+        }
+        let (Some(input), Some(setup)) = (MenuInput::from_event(event), self.setup.as_mut()) else {
+            return Ok(());
+        };
+        match setup.handle(input) {
+            Some(SetupAction::Start(config)) => {
+                if let Some(launcher) = self.launcher.as_mut() {
+                    let model = launcher.new_game(config)?;
+                    self.start_game(model, Some(config))?;
+                }
+            }
+            Some(SetupAction::Back) => self.state = AppState::MainMenu,
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn handle_settings(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        if event == InputEvent::GameQuit {
+            self.state = AppState::Closing;
+            return Ok(());
+        }
+        let (Some(input), Some(settings)) = (MenuInput::from_event(event), self.settings.as_mut())
+        else {
+            return Ok(());
+        };
+        let close = settings.handle(input);
+        self.camera.set_scale(settings.scale());
+        self.tick_duration = settings.tick_duration();
+        self.build_phase = settings.build_phase();
+        if close {
+            self.state = AppState::MainMenu;
+        }
+        Ok(())
+    }
+
+    fn handle_pause_menu(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        match event {
             InputEvent::GamePauseSwitch => {
-                self.state = if self.state == AppState::Paused {
-                    self.update_clock.tick();
-                    AppState::Running
-                } else {
-                    AppState::Paused
+                self.resume();
+                return Ok(());
+            }
+            InputEvent::GameQuit => {
+                self.game_model.quit();
+                self.state = AppState::Summary;
+                return Ok(());
+            }
+            _ => {}
+        }
+        let Some(input) = MenuInput::from_event(event) else {
+            return Ok(());
+        };
+        if input == MenuInput::Back {
+            self.state = AppState::Paused;
+            return Ok(());
+        }
+        match self.pause_menu.handle(input) {
+            Some(PauseMenuItem::Resume) => self.resume(),
+            Some(PauseMenuItem::Plan) => self.state = AppState::Paused,
+            Some(PauseMenuItem::Restart) => {
+                if let (Some(launcher), Some(config)) = (self.launcher.as_mut(), self.config) {
+                    let model = launcher.new_game(config)?;
+                    self.start_game(model, Some(config))?;
                 }
             }
+            Some(PauseMenuItem::Save) => self.save()?,
+            Some(PauseMenuItem::QuitToMenu) => self.state = AppState::MainMenu,
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn resume(&mut self) {
+        self.update_clock.tick();
+        self.state = AppState::Running;
+    }
+
+    fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        if let Some(launcher) = self.launcher.as_ref() {
+            launcher.save_game(&self.game_model)?;
+            self.toasts.info("Game saved");
+        }
+        Ok(())
+    }
+
+    fn save_recording(&mut self) -> io::Result<()> {
+        let Some(recording) = self.recording.as_mut() else {
+            return Ok(());
+        };
+        if let Some(replay) = recording.replay.as_mut() {
+            replay.finish(self.tick);
+            replay.save(&recording.replay_path)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the shown game with a fresh one, only games with a known setup
+    /// can be recorded
+    fn start_game(&mut self, model: G, config: Option<GameConfig>) -> io::Result<()> {
+        self.save_recording()?;
+        self.game_model = model;
+        self.input_context = InputContext::new(
+            self.game_model.min_tower_gap(),
+            self.game_model.content().towers,
+        );
+        self.camera.set_position(Point::default());
+        self.timestep = FixedTimestep::new(SIMULATION_STEP, Self::MAX_CATCH_UP_STEPS);
+        self.tick = 0;
+        self.toasts.clear();
+        self.config = config;
+        if let Some(recording) = self.recording.as_mut() {
+            recording.replay = config.map(|config| {
+                Replay::new(config, recording.content.clone(), recording.content_hash)
+            });
+            if recording.replay.is_some() {
+                recording.replay_path = Replay::unused_path(&recording.path);
+                let message = format!("Recording to {}", recording.replay_path.display());
+                self.toasts.info(message);
+            }
+        }
+        self.update_clock.tick();
+        self.state = AppState::Running;
+        Ok(())
+    }
+
+    /// Keeps the help from scrolling past its last line on the current screen,
+    /// only scrolling down and resizing can move it there
+    fn clamp_help_scroll(&mut self, event: InputEvent) -> io::Result<()> {
+        let moves_past_end = matches!(event, InputEvent::HelpScrollDown | InputEvent::Resize);
+        if !self.input_context.help_shown() || !moves_past_end {
+            return Ok(());
+        }
+        let help = HelpOverlay::new(
+            &self.keymap,
+            self.game_model.content().enemies,
+            self.input_context.help_scroll(),
+        );
+        let max_scroll = help.max_scroll(&self.camera, self.screen.size()?);
+        let scroll = self.input_context.help_scroll().min(max_scroll);
+        self.input_context.set_help_scroll(scroll);
+        Ok(())
+    }
+
+    fn record(
+        &mut self,
+        event: InputEvent,
+        screen_info: ScreenInfo,
+        cursor: Point,
+        affects_model: bool,
+    ) {
+        let Some(replay) = self
+            .recording
+            .as_mut()
+            .and_then(|recording| recording.replay.as_mut())
+        else {
+            return;
+        };
+        match event {
+            InputEvent::None
+            | InputEvent::Unknown
+            | InputEvent::OtherKey
+            | InputEvent::GameSave
+            | InputEvent::HelpSwitch
+            | InputEvent::HelpScrollUp
+            | InputEvent::HelpScrollDown
+            | InputEvent::Resize
+            | InputEvent::MouseMovedTo(_) => {}
+            _ => replay.record(ReplayEntry {
+                tick: self.tick,
+                event,
+                screen_info,
+                cursor,
+                affects_model,
+            }),
+        }
+    }
+
+    fn update_playback(&mut self, elapsed: Duration) -> io::Result<()> {
+        let Some(playback) = self.playback.as_ref() else {
+            return Ok(());
+        };
+        let speed = playback.player.speed();
+        let paused = playback.player.is_paused();
+
+        let steps = self.timestep.advance(elapsed);
+        if !paused {
+            for _ in 0..steps * speed {
+                if !self.replay_step() {
+                    break;
+                }
+            }
+        }
+
+        let Some(playback) = self.playback.as_ref() else {
+            return Ok(());
+        };
+        let last_tick = playback.player.replay().last_tick();
+        self.screen.set_paused(paused);
+        let status = ReplayStatus::new(
+            &self.keymap,
+            SIMULATION_STEP * self.tick as u32,
+            SIMULATION_STEP * last_tick as u32,
+            speed,
+            paused,
+        );
+        self.screen.draw_frame_with(
+            &self.camera,
+            &self.game_model,
+            &self.input_context,
+            &self.keymap,
+            &self.toasts,
+            &status,
+        )
+    }
+
+    fn handle_playback(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        self.camera.handle(event, &self.input_context)?;
+        let Some(playback) = self.playback.as_mut() else {
+            return Ok(());
+        };
+
+        match event {
+            InputEvent::GameQuit => self.state = AppState::Closing,
+            InputEvent::GamePauseSwitch => {
+                playback.player.switch_pause();
+                self.update_clock.tick();
+            }
+            InputEvent::ReplaySpeedUp => playback.player.speed_up(),
+            InputEvent::ReplaySlowDown => playback.player.slow_down(),
+            InputEvent::ReplaySeekForward => self.seek(self.tick + Self::SEEK_TICKS)?,
+            InputEvent::ReplaySeekBackward => {
+                self.seek(self.tick.saturating_sub(Self::SEEK_TICKS))?
+            }
+            InputEvent::HelpSwitch
+            | InputEvent::HelpScrollUp
+            | InputEvent::HelpScrollDown
+            | InputEvent::Resize => {
+                self.input_context.handle(event)?;
+                self.clamp_help_scroll(event)?;
+            }
             _ => {}
         }
         Ok(())
     }
+
+    /// Seeking backward restarts the simulation from a fresh model and
+    /// replays it up to the requested tick
+    fn seek(&mut self, target_tick: u64) -> Result<(), Box<dyn Error>> {
+        let Some(playback) = self.playback.as_mut() else {
+            return Ok(());
+        };
+        if target_tick < self.tick {
+            self.game_model = (playback.make_model)()?;
+            self.input_context = InputContext::new(
+                self.game_model.min_tower_gap(),
+                self.game_model.content().towers,
+            );
+            playback.player.rewind();
+            self.toasts.clear();
+            self.tick = 0;
+        }
+        while self.tick < target_tick && self.replay_step() {}
+        Ok(())
+    }
+
+    fn replay_step(&mut self) -> bool {
+        let Some(playback) = self.playback.as_mut() else {
+            return false;
+        };
+        let due = playback.player.take_due(self.tick).to_vec();
+        let finished = playback.player.is_finished(self.tick);
+
+        for entry in due {
+            self.input_context.set_screen_info(entry.screen_info);
+            self.input_context.set_cursor(entry.cursor);
+            let _ = self.input_context.handle(entry.event);
+            if entry.affects_model {
+                if let Err(err) = self.game_model.handle(entry.event, &self.input_context) {
+                    self.toasts.error(err.to_string());
+                }
+            }
+            if entry.event == InputEvent::GameQuit {
+                self.game_model.quit();
+            }
+        }
+
+        if finished || self.game_model.is_over() {
+            return false;
+        }
+        self.game_model.update(self.timestep.step());
+        self.notify_events();
+        self.tick += 1;
+        true
+    }
+
+    fn notify_events(&mut self) {
+        for event in self.game_model.drain_events() {
+            match event {
+                GameEvent::WaveStarted { wave } => {
+                    self.toasts.info(format!("Wave {} incoming", wave));
+                }
+                GameEvent::WaveEnded {
+                    wave,
+                    bonus,
+                    interest,
+                } => {
+                    let mut text = format!("Wave {} cleared", wave);
+                    if bonus > 0 {
+                        text += &format!(", +{}$ bonus", bonus);
+                    }
+                    if interest > 0 {
+                        text += &format!(", +{}$ interest", interest);
+                    }
+                    self.toasts.info(text);
+                }
+                GameEvent::EnemyLeaked {
+                    enemy, lives_lost, ..
+                } => {
+                    self.toasts
+                        .warning(format!("{} got through, -{} lives", enemy.name, lives_lost));
+                }
+                _ => {}
+            }
+        }
+    }
 }
@@ -4,7 +4,7 @@ use ratatui::{
 };
 
 use crate::{
-    model::{DamageType, EnemyInfo},
+    model::{EnemyInfo, Resistances},
     ui::PointDrawInfo,
 };
 
@@ -13,11 +13,17 @@ pub const COMMON_ENEMY_MODIFIERS: Modifier = Modifier::BOLD;
 pub const COMMON_ENEMY_FG_COLOR: Color = Color::Red;
 pub const COMMON_ENEMY_BG_COLOR: Color = Color::Reset;
 
+pub const SLOWED_ENEMY_FG_COLOR: Color = Color::Cyan;
+pub const BURNING_ENEMY_FG_COLOR: Color = Color::LightYellow;
+pub const STUNNED_ENEMY_FG_COLOR: Color = Color::Magenta;
+
 pub const BASIC_ENEMY_INFO: EnemyInfo = EnemyInfo {
+    name: "Basic enemy",
     max_health: 20.0,
     speed: 1.0,
     reward: 3,
-    damage_eval: |damage| damage.value,
+    leak_damage: 1,
+    resistances: Resistances::NONE,
     draw_info: PointDrawInfo {
         close_up_sprite: Some(""),
         marker: COMMON_ENEMY_MARKER,
@@ -28,15 +34,14 @@ pub const BASIC_ENEMY_INFO: EnemyInfo = EnemyInfo {
 };
 
 pub const KINNETIC_RESIST_ENEMY_INFO: EnemyInfo = EnemyInfo {
+    name: "Kinnetic resistant enemy",
     max_health: 20.0,
     speed: 1.0,
     reward: 4,
-    damage_eval: |damage| {
-        let coef = match damage.kind {
-            DamageType::Kinnetic => 0.3,
-            _ => 1.0,
-        };
-        damage.value * coef
+    leak_damage: 2,
+    resistances: Resistances {
+        kinnetic: 0.3,
+        magic: 1.0,
     },
     draw_info: PointDrawInfo {
         close_up_sprite: Some("󰟆"),
@@ -48,15 +53,14 @@ pub const KINNETIC_RESIST_ENEMY_INFO: EnemyInfo = EnemyInfo {
 };
 
 pub const MAGIC_RESIST_ENEMY_INFO: EnemyInfo = EnemyInfo {
+    name: "Magic resistant enemy",
     max_health: 20.0,
     speed: 1.0,
     reward: 4,
-    damage_eval: |damage| {
-        let coef = match damage.kind {
-            DamageType::Magic => 0.6,
-            _ => 1.0,
-        };
-        damage.value * coef
+    leak_damage: 2,
+    resistances: Resistances {
+        kinnetic: 1.0,
+        magic: 0.6,
     },
     draw_info: PointDrawInfo {
         close_up_sprite: Some("󰐁"),
@@ -66,3 +70,9 @@ pub const MAGIC_RESIST_ENEMY_INFO: EnemyInfo = EnemyInfo {
         bg_color: COMMON_ENEMY_BG_COLOR,
     },
 };
+
+pub const ENEMIES: &[EnemyInfo] = &[
+    BASIC_ENEMY_INFO,
+    KINNETIC_RESIST_ENEMY_INFO,
+    MAGIC_RESIST_ENEMY_INFO,
+];
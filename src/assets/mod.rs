@@ -1,3 +1,4 @@
 pub mod enemy;
 pub mod tower;
 pub mod tower_ui;
+pub mod wave;
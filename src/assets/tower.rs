@@ -6,8 +6,11 @@ use ratatui::{
 };
 
 use crate::{
-    model::{Damage, DamageType, ProjectileInfo, TowerInfo},
-    ui::PointDrawInfo,
+    model::{
+        Damage, DamageType, ProjectileInfo, Splash, StatusEffect, StatusEffectKind, TowerInfo,
+        TowerUpgrade,
+    },
+    ui::{CircleDrawInfo, PointDrawInfo},
 };
 
 pub const PROJECTILE_DRAW_INFO: PointDrawInfo = PointDrawInfo {
@@ -18,6 +21,11 @@ pub const PROJECTILE_DRAW_INFO: PointDrawInfo = PointDrawInfo {
     bg_color: Color::Reset,
 };
 
+pub const EXPLOSION_DRAW_INFO: CircleDrawInfo = CircleDrawInfo {
+    marker: Marker::Braille,
+    fg_color: Color::LightRed,
+};
+
 pub const COMMON_TOWER_MARKER: Marker = Marker::Dot;
 pub const COMMON_TOWER_MODIFIERS: Modifier = Modifier::BOLD;
 pub const COMMON_TOWER_FG_COLOR: Color = Color::Green;
@@ -35,6 +43,8 @@ pub const ARCHER_TOWER_INFO: TowerInfo = TowerInfo {
             value: 10.0,
             kind: DamageType::Kinnetic,
         },
+        effect: None,
+        splash: None,
     },
     draw_info: PointDrawInfo {
         close_up_sprite: Some(""),
@@ -43,6 +53,40 @@ pub const ARCHER_TOWER_INFO: TowerInfo = TowerInfo {
         fg_color: COMMON_TOWER_FG_COLOR,
         bg_color: COMMON_TOWER_BG_COLOR,
     },
+    upgrades: &[
+        TowerUpgrade {
+            cost: 15,
+            cooldown: Duration::from_millis(1200),
+            range: 8.0,
+            projectile_info: ProjectileInfo {
+                speed: 100.0,
+                damage: Damage {
+                    value: 14.0,
+                    kind: DamageType::Kinnetic,
+                },
+                effect: None,
+                splash: None,
+            },
+        },
+        TowerUpgrade {
+            cost: 25,
+            cooldown: Duration::from_millis(1000),
+            range: 9.0,
+            projectile_info: ProjectileInfo {
+                speed: 100.0,
+                damage: Damage {
+                    value: 20.0,
+                    kind: DamageType::Kinnetic,
+                },
+                effect: Some(StatusEffect {
+                    kind: StatusEffectKind::Stun,
+                    duration: Duration::from_millis(300),
+                    source: None,
+                }),
+                splash: None,
+            },
+        },
+    ],
 };
 
 pub const MAGE_TOWER_INFO: TowerInfo = TowerInfo {
@@ -57,6 +101,15 @@ pub const MAGE_TOWER_INFO: TowerInfo = TowerInfo {
             value: 5.0,
             kind: DamageType::Magic,
         },
+        effect: Some(StatusEffect {
+            kind: StatusEffectKind::Burn(Damage {
+                value: 2.0,
+                kind: DamageType::Magic,
+            }),
+            duration: Duration::from_secs(3),
+            source: None,
+        }),
+        splash: None,
     },
     draw_info: PointDrawInfo {
         close_up_sprite: Some(""),
@@ -65,4 +118,184 @@ pub const MAGE_TOWER_INFO: TowerInfo = TowerInfo {
         fg_color: COMMON_TOWER_FG_COLOR,
         bg_color: COMMON_TOWER_BG_COLOR,
     },
+    upgrades: &[
+        TowerUpgrade {
+            cost: 15,
+            cooldown: Duration::from_millis(1700),
+            range: 11.0,
+            projectile_info: ProjectileInfo {
+                speed: 50.0,
+                damage: Damage {
+                    value: 8.0,
+                    kind: DamageType::Magic,
+                },
+                effect: Some(StatusEffect {
+                    kind: StatusEffectKind::Burn(Damage {
+                        value: 3.0,
+                        kind: DamageType::Magic,
+                    }),
+                    duration: Duration::from_secs(3),
+                    source: None,
+                }),
+                splash: None,
+            },
+        },
+        TowerUpgrade {
+            cost: 25,
+            cooldown: Duration::from_millis(1400),
+            range: 12.0,
+            projectile_info: ProjectileInfo {
+                speed: 50.0,
+                damage: Damage {
+                    value: 12.0,
+                    kind: DamageType::Magic,
+                },
+                effect: Some(StatusEffect {
+                    kind: StatusEffectKind::Burn(Damage {
+                        value: 4.0,
+                        kind: DamageType::Magic,
+                    }),
+                    duration: Duration::from_secs(3),
+                    source: None,
+                }),
+                splash: None,
+            },
+        },
+    ],
+};
+
+pub const FROST_TOWER_INFO: TowerInfo = TowerInfo {
+    cooldown: Duration::from_millis(1500),
+    cost: 15,
+    range: 8.0,
+    name: "Frost tower",
+    description: "Slows enemies down",
+    projectile_info: ProjectileInfo {
+        speed: 70.0,
+        damage: Damage {
+            value: 2.0,
+            kind: DamageType::Magic,
+        },
+        effect: Some(StatusEffect {
+            kind: StatusEffectKind::Slow(0.5),
+            duration: Duration::from_secs(2),
+            source: None,
+        }),
+        splash: None,
+    },
+    draw_info: PointDrawInfo {
+        close_up_sprite: Some("󰜗"),
+        marker: COMMON_TOWER_MARKER,
+        modifiers: COMMON_TOWER_MODIFIERS,
+        fg_color: COMMON_TOWER_FG_COLOR,
+        bg_color: COMMON_TOWER_BG_COLOR,
+    },
+    upgrades: &[
+        TowerUpgrade {
+            cost: 20,
+            cooldown: Duration::from_millis(1300),
+            range: 9.0,
+            projectile_info: ProjectileInfo {
+                speed: 70.0,
+                damage: Damage {
+                    value: 3.0,
+                    kind: DamageType::Magic,
+                },
+                effect: Some(StatusEffect {
+                    kind: StatusEffectKind::Slow(0.4),
+                    duration: Duration::from_millis(2500),
+                    source: None,
+                }),
+                splash: None,
+            },
+        },
+        TowerUpgrade {
+            cost: 30,
+            cooldown: Duration::from_millis(1100),
+            range: 10.0,
+            projectile_info: ProjectileInfo {
+                speed: 70.0,
+                damage: Damage {
+                    value: 4.0,
+                    kind: DamageType::Magic,
+                },
+                effect: Some(StatusEffect {
+                    kind: StatusEffectKind::Slow(0.3),
+                    duration: Duration::from_secs(3),
+                    source: None,
+                }),
+                splash: None,
+            },
+        },
+    ],
+};
+
+pub const CANNON_TOWER_INFO: TowerInfo = TowerInfo {
+    cooldown: Duration::from_millis(3000),
+    cost: 25,
+    range: 9.0,
+    name: "Cannon tower",
+    description: "Shells explode damaging every enemy nearby",
+    projectile_info: ProjectileInfo {
+        speed: 30.0,
+        damage: Damage {
+            value: 12.0,
+            kind: DamageType::Kinnetic,
+        },
+        effect: None,
+        splash: Some(Splash {
+            radius: 3.0,
+            falloff: 0.5,
+        }),
+    },
+    draw_info: PointDrawInfo {
+        close_up_sprite: Some("󰈸"),
+        marker: COMMON_TOWER_MARKER,
+        modifiers: COMMON_TOWER_MODIFIERS,
+        fg_color: COMMON_TOWER_FG_COLOR,
+        bg_color: COMMON_TOWER_BG_COLOR,
+    },
+    upgrades: &[
+        TowerUpgrade {
+            cost: 30,
+            cooldown: Duration::from_millis(2700),
+            range: 10.0,
+            projectile_info: ProjectileInfo {
+                speed: 30.0,
+                damage: Damage {
+                    value: 18.0,
+                    kind: DamageType::Kinnetic,
+                },
+                effect: None,
+                splash: Some(Splash {
+                    radius: 3.5,
+                    falloff: 0.5,
+                }),
+            },
+        },
+        TowerUpgrade {
+            cost: 45,
+            cooldown: Duration::from_millis(2400),
+            range: 11.0,
+            projectile_info: ProjectileInfo {
+                speed: 30.0,
+                damage: Damage {
+                    value: 26.0,
+                    kind: DamageType::Kinnetic,
+                },
+                effect: None,
+                splash: Some(Splash {
+                    radius: 4.0,
+                    falloff: 0.5,
+                }),
+            },
+        },
+    ],
 };
+
+pub const TOWERS: &[TowerInfo] = &[
+    ARCHER_TOWER_INFO,
+    MAGE_TOWER_INFO,
+    FROST_TOWER_INFO,
+    CANNON_TOWER_INFO,
+];
@@ -11,3 +11,9 @@ pub const TOWER_RADIUS_DRAW_INFO: CircleDrawInfo = CircleDrawInfo {
     marker: Braille,
     fg_color: Color::Gray,
 };
+
+/// Used for both placement preview circles when a tower can't be built under the cursor
+pub const INVALID_PLACEMENT_DRAW_INFO: CircleDrawInfo = CircleDrawInfo {
+    marker: Braille,
+    fg_color: Color::Red,
+};
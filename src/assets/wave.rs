@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::{
+    assets::enemy::{BASIC_ENEMY_INFO, KINNETIC_RESIST_ENEMY_INFO, MAGIC_RESIST_ENEMY_INFO},
+    model::{EnemyGroup, Wave},
+};
+
+pub const DEFAULT_WAVE_SCHEDULE: &[Wave] = &[
+    Wave {
+        delay: Duration::from_secs(5),
+        bonus: 25,
+        groups: &[EnemyGroup {
+            enemy_info: &BASIC_ENEMY_INFO,
+            count: 5,
+            interval: Duration::from_millis(1500),
+        }],
+    },
+    Wave {
+        delay: Duration::from_secs(10),
+        bonus: 50,
+        groups: &[
+            EnemyGroup {
+                enemy_info: &BASIC_ENEMY_INFO,
+                count: 5,
+                interval: Duration::from_millis(1000),
+            },
+            EnemyGroup {
+                enemy_info: &KINNETIC_RESIST_ENEMY_INFO,
+                count: 3,
+                interval: Duration::from_millis(1500),
+            },
+        ],
+    },
+    Wave {
+        delay: Duration::from_secs(10),
+        bonus: 75,
+        groups: &[
+            EnemyGroup {
+                enemy_info: &MAGIC_RESIST_ENEMY_INFO,
+                count: 5,
+                interval: Duration::from_millis(1000),
+            },
+            EnemyGroup {
+                enemy_info: &KINNETIC_RESIST_ENEMY_INFO,
+                count: 5,
+                interval: Duration::from_millis(1000),
+            },
+        ],
+    },
+    Wave {
+        delay: Duration::from_secs(10),
+        bonus: 100,
+        groups: &[
+            EnemyGroup {
+                enemy_info: &BASIC_ENEMY_INFO,
+                count: 10,
+                interval: Duration::from_millis(500),
+            },
+            EnemyGroup {
+                enemy_info: &MAGIC_RESIST_ENEMY_INFO,
+                count: 8,
+                interval: Duration::from_millis(700),
+            },
+            EnemyGroup {
+                enemy_info: &KINNETIC_RESIST_ENEMY_INFO,
+                count: 8,
+                interval: Duration::from_millis(700),
+            },
+        ],
+    },
+];
@@ -0,0 +1,150 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::Parser;
+
+use crate::{
+    config::{Difficulty, GameConfig, RoadKind, SpawnerKind},
+    model::{MapBounds, Point},
+    sim::ReportFormat,
+};
+
+#[derive(Debug, Clone, Parser)]
+#[command(version, about = "Tower defence game rendered directly in terminal")]
+pub struct Cli {
+    /// Seed for road generation and enemy spawning, random if not given
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Road generation algorithm
+    #[arg(long, value_enum, default_value_t = RoadKind::Noise)]
+    pub road: RoadKind,
+
+    /// Enemy spawning algorithm
+    #[arg(long, value_enum, default_value_t = SpawnerKind::Waves)]
+    pub spawner: SpawnerKind,
+
+    /// Starting balance, depends on difficulty if not given
+    #[arg(long)]
+    pub money: Option<u64>,
+
+    /// Percent of banked gold paid as interest at the end of every wave
+    #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u64).range(..=100))]
+    pub interest: Option<u64>,
+
+    /// Affects starting lives and balance, and the spawn rate of the random spawner
+    #[arg(long, value_enum, default_value_t = Difficulty::Normal)]
+    pub difficulty: Difficulty,
+
+    /// Minimal distance between towers and the road
+    #[arg(long, value_name = "DISTANCE")]
+    pub road_clearance: Option<f32>,
+
+    /// Area where towers can be built, as `min_x,min_y,max_x,max_y`
+    #[arg(long, value_name = "RECT", value_parser = parse_bounds)]
+    pub bounds: Option<MapBounds>,
+
+    /// Allow building, selling and upgrading towers while the game is paused
+    #[arg(long)]
+    pub build_phase: bool,
+
+    /// Target frames per second
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u32).range(1..))]
+    pub fps: u32,
+
+    /// Initial camera scale, less is closer
+    #[arg(long, default_value_t = 1.0, value_parser = parse_scale)]
+    pub scale: f32,
+
+    /// TOML file with enemy, tower and wave definitions, built-in content if not given
+    #[arg(long)]
+    pub content: Option<PathBuf>,
+
+    /// Key bindings file, `~/.config/tdtui/keys.toml` is used if it exists
+    #[arg(long, value_name = "FILE")]
+    pub keys: Option<PathBuf>,
+
+    /// Record each game started from the menu into its own replay file, numbered
+    /// (game-2.json, game-3.json...) when the name is taken
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["replay", "load"])]
+    pub record: Option<PathBuf>,
+
+    /// Play back a recorded replay file instead of starting a new game
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["seed", "road", "spawner", "money", "interest", "difficulty", "road_clearance", "bounds", "content"]
+    )]
+    pub replay: Option<PathBuf>,
+
+    /// File written when the game is saved with Ctrl+S
+    #[arg(long, value_name = "FILE", default_value = "tdtui-save.json")]
+    pub save: PathBuf,
+
+    /// Continue a game saved earlier
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["seed", "road", "spawner", "money", "interest", "difficulty", "road_clearance", "bounds", "content", "replay"]
+    )]
+    pub load: Option<PathBuf>,
+
+    /// Run a scripted game without a terminal and print statistics
+    #[arg(long, value_name = "SCRIPT", conflicts_with_all = ["replay", "load", "record"])]
+    pub simulate: Option<PathBuf>,
+
+    /// Number of simulated runs, each uses the next seed
+    #[arg(long, default_value_t = 1, requires = "simulate", value_parser = clap::value_parser!(u64).range(1..))]
+    pub runs: u64,
+
+    /// Statistics format of simulated runs
+    #[arg(long, value_enum, default_value_t = ReportFormat::Json, requires = "simulate")]
+    pub format: ReportFormat,
+
+    /// File for simulation statistics, standard output if not given
+    #[arg(long, value_name = "FILE", requires = "simulate")]
+    pub output: Option<PathBuf>,
+}
+
+impl Cli {
+    pub fn tick_duration(&self) -> Duration {
+        Duration::from_millis(1000) / self.fps
+    }
+
+    pub fn game_config(&self) -> GameConfig {
+        GameConfig {
+            seed: self.seed.unwrap_or_else(rand::random),
+            road: self.road,
+            spawner: self.spawner,
+            difficulty: self.difficulty,
+            initial_balance: self.money,
+            interest: self.interest,
+            road_clearance: self.road_clearance,
+            bounds: self.bounds,
+        }
+    }
+}
+
+/// Only the format is checked here, the game config validates the values
+fn parse_bounds(arg: &str) -> Result<MapBounds, String> {
+    let coords = arg
+        .split(',')
+        .map(|coord| coord.trim().parse::<f32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("{}", err))?;
+    let [min_x, min_y, max_x, max_y] = coords[..] else {
+        return Err(String::from("expected four comma separated numbers"));
+    };
+    Ok(MapBounds {
+        min: Point { x: min_x, y: min_y },
+        max: Point { x: max_x, y: max_y },
+    })
+}
+
+fn parse_scale(arg: &str) -> Result<f32, String> {
+    let scale: f32 = arg.parse().map_err(|err| format!("{}", err))?;
+    if scale.is_finite() && scale > 0.0 {
+        Ok(scale)
+    } else {
+        Err(String::from("scale must be positive"))
+    }
+}
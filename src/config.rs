@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use noise::Perlin;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::Content,
+    model::{
+        AnySpawner, AnyTrajectory, ConcreteGameModel, GameRng, Interest, MapBounds,
+        NoiseTrajectory, RandomizedSpawnerWithCooldown, SettingErr, SineTrajectory, WaveSpawner,
+    },
+};
+
+pub type Game = ConcreteGameModel<AnySpawner, AnyTrajectory>;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum, Serialize, Deserialize,
+)]
+pub enum RoadKind {
+    /// Smooth random road generated from Perlin noise
+    Noise,
+    /// Regular sine wave shifted by seed
+    Sine,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum, Serialize, Deserialize,
+)]
+pub enum SpawnerKind {
+    /// Scheduled waves with breaks between them, the game is won after the last one
+    Waves,
+    /// Endless stream of random enemies
+    Random,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum, Serialize, Deserialize,
+)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn initial_lives(&self) -> u64 {
+        match self {
+            Difficulty::Easy => 30,
+            Difficulty::Normal => 20,
+            Difficulty::Hard => 10,
+        }
+    }
+
+    pub fn initial_balance(&self) -> u64 {
+        match self {
+            Difficulty::Easy => 1500,
+            Difficulty::Normal => 1000,
+            Difficulty::Hard => 600,
+        }
+    }
+
+    pub fn spawn_cooldown(&self) -> Duration {
+        match self {
+            Difficulty::Easy => Duration::from_millis(1500),
+            Difficulty::Normal => Duration::from_millis(1000),
+            Difficulty::Hard => Duration::from_millis(700),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct GameConfig {
+    pub seed: u64,
+    pub road: RoadKind,
+    pub spawner: SpawnerKind,
+    pub difficulty: Difficulty,
+    pub initial_balance: Option<u64>,
+    /// Percent of banked gold paid at the end of every wave
+    #[serde(default)]
+    pub interest: Option<u64>,
+    /// Minimal distance between towers and the road, model default if not given
+    #[serde(default)]
+    pub road_clearance: Option<f32>,
+    /// Area where towers can be built, model default if not given
+    #[serde(default)]
+    pub bounds: Option<MapBounds>,
+}
+
+impl GameConfig {
+    const MIN_TOWER_GAP: f32 = 1.0;
+
+    /// Fails if the road clearance or map bounds are invalid
+    pub fn build_model(&self, content: Content) -> Result<Game, SettingErr> {
+        let road = self.road;
+        let mut model = ConcreteGameModel::new(
+            content,
+            self.seed,
+            self.build_spawner(content),
+            |rng| Self::build_trajectory(road, rng),
+            self.initial_balance
+                .unwrap_or(self.difficulty.initial_balance()),
+            self.difficulty.initial_lives(),
+            Self::MIN_TOWER_GAP,
+        );
+        model.set_interest(self.interest.map(Interest::new));
+        if let Some(clearance) = self.road_clearance {
+            model.set_road_clearance(clearance)?;
+        }
+        if let Some(bounds) = self.bounds {
+            model.set_bounds(bounds)?;
+        }
+        Ok(model)
+    }
+
+    fn build_spawner(&self, content: Content) -> AnySpawner {
+        match self.spawner {
+            SpawnerKind::Waves => AnySpawner::Waves(WaveSpawner::new(content.waves)),
+            SpawnerKind::Random => AnySpawner::Randomized(RandomizedSpawnerWithCooldown::new(
+                self.difficulty.spawn_cooldown(),
+                content.enemies,
+            )),
+        }
+    }
+
+    fn build_trajectory(road: RoadKind, rng: &mut GameRng) -> AnyTrajectory {
+        match road {
+            RoadKind::Noise => {
+                AnyTrajectory::Noise(Box::new(NoiseTrajectory::new(Perlin::new(rng.gen()))))
+            }
+            RoadKind::Sine => {
+                let phase = rng.gen_range(0.0..std::f32::consts::TAU);
+                AnyTrajectory::Sine(SineTrajectory::new(phase))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Point;
+
+    fn config() -> GameConfig {
+        GameConfig {
+            seed: 1,
+            road: RoadKind::Sine,
+            spawner: SpawnerKind::Waves,
+            difficulty: Difficulty::Normal,
+            initial_balance: None,
+            interest: None,
+            road_clearance: None,
+            bounds: None,
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_road_clearance() {
+        let content = Content::builtin();
+        for clearance in [-1.0, f32::INFINITY] {
+            let config = GameConfig {
+                road_clearance: Some(clearance),
+                ..config()
+            };
+            assert_eq!(
+                config.build_model(content).err(),
+                Some(SettingErr::RoadClearance(clearance))
+            );
+        }
+        let config = GameConfig {
+            road_clearance: Some(3.0),
+            ..config()
+        };
+        assert_eq!(config.build_model(content).unwrap().road_clearance(), 3.0);
+    }
+
+    #[test]
+    fn rejects_invalid_bounds() {
+        let content = Content::builtin();
+        let inverted = MapBounds {
+            min: Point { x: 10.0, y: 0.0 },
+            max: Point { x: 0.0, y: 10.0 },
+        };
+        let config = GameConfig {
+            bounds: Some(inverted),
+            ..config()
+        };
+        assert_eq!(
+            config.build_model(content).err(),
+            Some(SettingErr::Bounds(inverted))
+        );
+    }
+}
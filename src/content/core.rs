@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use crate::{
+    assets::{enemy::ENEMIES, tower::TOWERS, wave::DEFAULT_WAVE_SCHEDULE},
+    model::{EnemyInfo, TowerInfo, Wave},
+};
+
+use super::loader::{content_file_hash, load_content, ContentErr};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Content {
+    pub enemies: &'static [EnemyInfo],
+    pub towers: &'static [TowerInfo],
+    pub waves: &'static [Wave],
+}
+
+impl Content {
+    pub fn builtin() -> Self {
+        Self {
+            enemies: ENEMIES,
+            towers: TOWERS,
+            waves: DEFAULT_WAVE_SCHEDULE,
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ContentErr> {
+        load_content(path.as_ref())
+    }
+
+    /// Tells whether a content file changed since a game was recorded with it
+    pub fn file_hash(path: impl AsRef<Path>) -> Result<u64, ContentErr> {
+        content_file_hash(path.as_ref())
+    }
+
+    pub fn enemy(&self, name: &str) -> Option<&'static EnemyInfo> {
+        self.enemies.iter().find(|enemy| enemy.name == name)
+    }
+
+    pub fn tower(&self, name: &str) -> Option<&'static TowerInfo> {
+        self.towers.iter().find(|tower| tower.name == name)
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
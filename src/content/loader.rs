@@ -0,0 +1,605 @@
+use std::{collections::HashSet, error::Error, fmt::Display, fs, io, path::Path, time::Duration};
+
+use ratatui::{
+    style::{Color, Modifier},
+    symbols::Marker,
+};
+use serde::Deserialize;
+
+use crate::{
+    assets::{
+        enemy::{
+            COMMON_ENEMY_BG_COLOR, COMMON_ENEMY_FG_COLOR, COMMON_ENEMY_MARKER,
+            COMMON_ENEMY_MODIFIERS,
+        },
+        tower::{
+            COMMON_TOWER_BG_COLOR, COMMON_TOWER_FG_COLOR, COMMON_TOWER_MARKER,
+            COMMON_TOWER_MODIFIERS,
+        },
+        wave::DEFAULT_WAVE_SCHEDULE,
+    },
+    model::{
+        Damage, EnemyGroup, EnemyInfo, ProjectileInfo, Resistances, Splash, StatusEffect,
+        StatusEffectKind, TowerInfo, TowerUpgrade, Wave,
+    },
+    ui::PointDrawInfo,
+};
+
+use super::core::Content;
+
+#[derive(Debug)]
+pub enum ContentErr {
+    Read(String, io::Error),
+    Parse(String, toml::de::Error),
+    Invalid(String, String),
+}
+
+impl Display for ContentErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentErr::Read(path, err) => {
+                write!(f, "Failed to read content file {}: {}", path, err)
+            }
+            ContentErr::Parse(path, err) => {
+                write!(f, "Failed to parse content file {}: {}", path, err)
+            }
+            ContentErr::Invalid(context, msg) => {
+                write!(f, "Invalid content in {}: {}", context, msg)
+            }
+        }
+    }
+}
+
+impl Error for ContentErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ContentErr::Read(_, err) => Some(err),
+            ContentErr::Parse(_, err) => Some(err),
+            ContentErr::Invalid(_, _) => None,
+        }
+    }
+}
+
+fn invalid<T>(context: &str, msg: impl Into<String>) -> Result<T, ContentErr> {
+    Err(ContentErr::Invalid(context.to_string(), msg.into()))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawContent {
+    enemies: Vec<RawEnemy>,
+    towers: Vec<RawTower>,
+    waves: Option<Vec<RawWave>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDrawInfo {
+    sprite: Option<String>,
+    marker: Option<String>,
+    modifiers: Option<Vec<String>>,
+    fg_color: Option<String>,
+    bg_color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawEnemy {
+    name: String,
+    max_health: f32,
+    speed: f32,
+    reward: u64,
+    leak_damage: u64,
+    #[serde(default)]
+    resistances: Resistances,
+    #[serde(default)]
+    draw: RawDrawInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+enum RawEffect {
+    Slow { factor: f32, duration_ms: u64 },
+    Burn { damage: Damage, duration_ms: u64 },
+    Stun { duration_ms: u64 },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawProjectile {
+    speed: f32,
+    damage: Damage,
+    effect: Option<RawEffect>,
+    splash: Option<Splash>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawUpgrade {
+    cost: u64,
+    cooldown_ms: u64,
+    range: f32,
+    projectile: RawProjectile,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawTower {
+    name: String,
+    #[serde(default)]
+    description: String,
+    cost: u64,
+    cooldown_ms: u64,
+    range: f32,
+    projectile: RawProjectile,
+    #[serde(default)]
+    upgrades: Vec<RawUpgrade>,
+    #[serde(default)]
+    draw: RawDrawInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawGroup {
+    enemy: String,
+    count: usize,
+    interval_ms: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawWave {
+    delay_ms: u64,
+    #[serde(default)]
+    bonus: u64,
+    groups: Vec<RawGroup>,
+}
+
+pub fn load_content(path: &Path) -> Result<Content, ContentErr> {
+    let path_str = path.display().to_string();
+    let text = fs::read_to_string(path).map_err(|err| ContentErr::Read(path_str.clone(), err))?;
+    parse_content(&text, &path_str)
+}
+
+/// FNV-1a hash of the content file, stable across builds so it can be stored
+pub fn content_file_hash(path: &Path) -> Result<u64, ContentErr> {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let bytes = fs::read(path).map_err(|err| ContentErr::Read(path.display().to_string(), err))?;
+    Ok(bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    }))
+}
+
+pub fn parse_content(text: &str, source: &str) -> Result<Content, ContentErr> {
+    let raw: RawContent =
+        toml::from_str(text).map_err(|err| ContentErr::Parse(source.to_string(), err))?;
+
+    if raw.enemies.is_empty() {
+        return invalid("enemies", "at least one enemy type is required");
+    }
+    if raw.towers.is_empty() {
+        return invalid("towers", "at least one tower type is required");
+    }
+    check_unique_names("enemies", raw.enemies.iter().map(|enemy| &enemy.name))?;
+    check_unique_names("towers", raw.towers.iter().map(|tower| &tower.name))?;
+
+    let enemies = raw
+        .enemies
+        .into_iter()
+        .map(convert_enemy)
+        .collect::<Result<Vec<_>, _>>()?
+        .leak();
+    let towers = raw
+        .towers
+        .into_iter()
+        .map(convert_tower)
+        .collect::<Result<Vec<_>, _>>()?
+        .leak();
+    let waves = match raw.waves {
+        Some(waves) => convert_waves(waves, enemies)?,
+        None => remap_default_waves(enemies)?,
+    };
+
+    Ok(Content {
+        enemies,
+        towers,
+        waves,
+    })
+}
+
+fn leak_str(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+fn check_unique_names<'a>(
+    context: &str,
+    names: impl Iterator<Item = &'a String>,
+) -> Result<(), ContentErr> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return invalid(context, format!("name \"{}\" is used more than once", name));
+        }
+    }
+    Ok(())
+}
+
+fn check_positive(context: &str, field: &str, value: f32) -> Result<(), ContentErr> {
+    if value.is_finite() && value > 0.0 {
+        return Ok(());
+    }
+    invalid(
+        context,
+        format!("{} must be positive, got {}", field, value),
+    )
+}
+
+fn check_fraction(context: &str, field: &str, value: f32) -> Result<(), ContentErr> {
+    if (0.0..=1.0).contains(&value) {
+        return Ok(());
+    }
+    invalid(
+        context,
+        format!("{} must be within [0, 1], got {}", field, value),
+    )
+}
+
+fn convert_enemy(raw: RawEnemy) -> Result<EnemyInfo, ContentErr> {
+    let context = format!("enemy \"{}\"", raw.name);
+    check_positive(&context, "max_health", raw.max_health)?;
+    check_positive(&context, "speed", raw.speed)?;
+    for (field, value) in [
+        ("resistances.kinnetic", raw.resistances.kinnetic),
+        ("resistances.magic", raw.resistances.magic),
+    ] {
+        if !value.is_finite() || value < 0.0 {
+            return invalid(
+                &context,
+                format!("{} must not be negative, got {}", field, value),
+            );
+        }
+    }
+
+    let default_draw_info = PointDrawInfo {
+        close_up_sprite: None,
+        marker: COMMON_ENEMY_MARKER,
+        modifiers: COMMON_ENEMY_MODIFIERS,
+        fg_color: COMMON_ENEMY_FG_COLOR,
+        bg_color: COMMON_ENEMY_BG_COLOR,
+    };
+    Ok(EnemyInfo {
+        draw_info: convert_draw_info(&context, raw.draw, default_draw_info)?,
+        name: leak_str(raw.name),
+        max_health: raw.max_health,
+        speed: raw.speed,
+        reward: raw.reward,
+        leak_damage: raw.leak_damage,
+        resistances: raw.resistances,
+    })
+}
+
+fn convert_tower(raw: RawTower) -> Result<TowerInfo, ContentErr> {
+    let context = format!("tower \"{}\"", raw.name);
+    check_positive(&context, "range", raw.range)?;
+    if raw.cost == 0 {
+        return invalid(&context, "cost must be positive");
+    }
+    if raw.cooldown_ms == 0 {
+        return invalid(&context, "cooldown_ms must be positive");
+    }
+
+    let upgrades = raw
+        .upgrades
+        .into_iter()
+        .enumerate()
+        .map(|(idx, upgrade)| {
+            let context = format!("{} upgrade {}", context, idx + 1);
+            check_positive(&context, "range", upgrade.range)?;
+            if upgrade.cost == 0 {
+                return invalid(&context, "cost must be positive");
+            }
+            if upgrade.cooldown_ms == 0 {
+                return invalid(&context, "cooldown_ms must be positive");
+            }
+            Ok(TowerUpgrade {
+                cost: upgrade.cost,
+                cooldown: Duration::from_millis(upgrade.cooldown_ms),
+                range: upgrade.range,
+                projectile_info: convert_projectile(&context, upgrade.projectile)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .leak();
+
+    let default_draw_info = PointDrawInfo {
+        close_up_sprite: None,
+        marker: COMMON_TOWER_MARKER,
+        modifiers: COMMON_TOWER_MODIFIERS,
+        fg_color: COMMON_TOWER_FG_COLOR,
+        bg_color: COMMON_TOWER_BG_COLOR,
+    };
+    Ok(TowerInfo {
+        cooldown: Duration::from_millis(raw.cooldown_ms),
+        cost: raw.cost,
+        range: raw.range,
+        projectile_info: convert_projectile(&context, raw.projectile)?,
+        draw_info: convert_draw_info(&context, raw.draw, default_draw_info)?,
+        upgrades,
+        name: leak_str(raw.name),
+        description: leak_str(raw.description),
+    })
+}
+
+fn convert_projectile(context: &str, raw: RawProjectile) -> Result<ProjectileInfo, ContentErr> {
+    check_positive(context, "projectile.speed", raw.speed)?;
+    if !raw.damage.value.is_finite() || raw.damage.value < 0.0 {
+        return invalid(context, "projectile.damage.value must not be negative");
+    }
+    if let Some(splash) = raw.splash {
+        check_positive(context, "projectile.splash.radius", splash.radius)?;
+        check_fraction(context, "projectile.splash.falloff", splash.falloff)?;
+    }
+
+    let effect = match raw.effect {
+        Some(RawEffect::Slow {
+            factor,
+            duration_ms,
+        }) => {
+            check_fraction(context, "projectile.effect.factor", factor)?;
+            Some(StatusEffect {
+                kind: StatusEffectKind::Slow(factor),
+                duration: Duration::from_millis(duration_ms),
+                source: None,
+            })
+        }
+        Some(RawEffect::Burn {
+            damage,
+            duration_ms,
+        }) => {
+            check_positive(context, "projectile.effect.damage.value", damage.value)?;
+            Some(StatusEffect {
+                kind: StatusEffectKind::Burn(damage),
+                duration: Duration::from_millis(duration_ms),
+                source: None,
+            })
+        }
+        Some(RawEffect::Stun { duration_ms }) => Some(StatusEffect {
+            kind: StatusEffectKind::Stun,
+            duration: Duration::from_millis(duration_ms),
+            source: None,
+        }),
+        None => None,
+    };
+
+    Ok(ProjectileInfo {
+        speed: raw.speed,
+        damage: raw.damage,
+        effect,
+        splash: raw.splash,
+    })
+}
+
+fn convert_draw_info(
+    context: &str,
+    raw: RawDrawInfo,
+    default: PointDrawInfo,
+) -> Result<PointDrawInfo, ContentErr> {
+    let parse_color = |field: &str, color: Option<String>, default: Color| match color {
+        Some(color) => color
+            .parse()
+            .or_else(|_| invalid(context, format!("unknown {} \"{}\"", field, color))),
+        None => Ok(default),
+    };
+
+    Ok(PointDrawInfo {
+        close_up_sprite: raw.sprite.map(leak_str).or(default.close_up_sprite),
+        marker: match raw.marker {
+            Some(marker) => parse_marker(context, &marker)?,
+            None => default.marker,
+        },
+        modifiers: match raw.modifiers {
+            Some(modifiers) => modifiers.iter().try_fold(Modifier::empty(), |acc, name| {
+                Ok(acc | parse_modifier(context, name)?)
+            })?,
+            None => default.modifiers,
+        },
+        fg_color: parse_color("draw.fg_color", raw.fg_color, default.fg_color)?,
+        bg_color: parse_color("draw.bg_color", raw.bg_color, default.bg_color)?,
+    })
+}
+
+fn parse_marker(context: &str, name: &str) -> Result<Marker, ContentErr> {
+    match name {
+        "dot" => Ok(Marker::Dot),
+        "block" => Ok(Marker::Block),
+        "bar" => Ok(Marker::Bar),
+        "braille" => Ok(Marker::Braille),
+        "half_block" => Ok(Marker::HalfBlock),
+        _ => invalid(context, format!("unknown draw.marker \"{}\"", name)),
+    }
+}
+
+fn parse_modifier(context: &str, name: &str) -> Result<Modifier, ContentErr> {
+    match name {
+        "bold" => Ok(Modifier::BOLD),
+        "dim" => Ok(Modifier::DIM),
+        "italic" => Ok(Modifier::ITALIC),
+        "underlined" => Ok(Modifier::UNDERLINED),
+        "slow_blink" => Ok(Modifier::SLOW_BLINK),
+        "rapid_blink" => Ok(Modifier::RAPID_BLINK),
+        "reversed" => Ok(Modifier::REVERSED),
+        "hidden" => Ok(Modifier::HIDDEN),
+        "crossed_out" => Ok(Modifier::CROSSED_OUT),
+        _ => invalid(
+            context,
+            format!("unknown draw.modifiers entry \"{}\"", name),
+        ),
+    }
+}
+
+fn find_enemy(
+    context: &str,
+    enemies: &'static [EnemyInfo],
+    name: &str,
+) -> Result<&'static EnemyInfo, ContentErr> {
+    match enemies.iter().find(|enemy| enemy.name == name) {
+        Some(enemy) => Ok(enemy),
+        None => invalid(context, format!("unknown enemy \"{}\"", name)),
+    }
+}
+
+fn convert_waves(
+    raw: Vec<RawWave>,
+    enemies: &'static [EnemyInfo],
+) -> Result<&'static [Wave], ContentErr> {
+    if raw.is_empty() {
+        return invalid("waves", "at least one wave is required");
+    }
+
+    let waves = raw
+        .into_iter()
+        .enumerate()
+        .map(|(idx, wave)| {
+            let context = format!("wave {}", idx + 1);
+            let groups = wave
+                .groups
+                .into_iter()
+                .map(|group| {
+                    Ok(EnemyGroup {
+                        enemy_info: find_enemy(&context, enemies, &group.enemy)?,
+                        count: group.count,
+                        interval: Duration::from_millis(group.interval_ms),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .leak();
+            Ok(Wave {
+                delay: Duration::from_millis(wave.delay_ms),
+                bonus: wave.bonus,
+                groups,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(waves.leak())
+}
+
+fn remap_default_waves(enemies: &'static [EnemyInfo]) -> Result<&'static [Wave], ContentErr> {
+    let context = "default wave schedule (no waves given)";
+    let waves = DEFAULT_WAVE_SCHEDULE
+        .iter()
+        .map(|wave| {
+            let groups = wave
+                .groups
+                .iter()
+                .map(|group| {
+                    Ok(EnemyGroup {
+                        enemy_info: find_enemy(context, enemies, group.enemy_info.name)?,
+                        ..*group
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .leak();
+            Ok(Wave {
+                delay: wave.delay,
+                bonus: wave.bonus,
+                groups,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(waves.leak())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENEMY: &str = r#"
+[[enemies]]
+name = "a"
+max_health = 10.0
+speed = 1.0
+reward = 1
+leak_damage = 1
+"#;
+
+    const TOWER: &str = r#"
+[[towers]]
+name = "t"
+cost = 10
+cooldown_ms = 1000
+range = 5.0
+projectile = { speed = 10.0, damage = { value = 1.0, kind = "magic" } }
+"#;
+
+    const WAVES: &str = r#"
+[[waves]]
+delay_ms = 1000
+groups = [{ enemy = "a", count = 1, interval_ms = 100 }]
+"#;
+
+    fn parse(enemy: &str, tower: &str) -> Result<Content, ContentErr> {
+        parse_content(&format!("{}{}{}", enemy, tower, WAVES), "test")
+    }
+
+    fn assert_invalid(result: Result<Content, ContentErr>, expected: &str) {
+        match result {
+            Err(ContentErr::Invalid(_, msg)) => assert!(msg.contains(expected), "{}", msg),
+            other => panic!("expected invalid content, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn accepts_minimal_and_bundled_content() {
+        parse(ENEMY, TOWER).unwrap();
+        parse_content(include_str!("../../data/content.toml"), "data/content.toml").unwrap();
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let enemy = ENEMY.replace("speed = 1.0", "speed = 1.0\nspeeed = 2.0");
+        assert!(matches!(parse(&enemy, TOWER), Err(ContentErr::Parse(_, _))));
+    }
+
+    #[test]
+    fn rejects_non_positive_health() {
+        for health in ["0.0", "-5.0"] {
+            let enemy = ENEMY.replace("max_health = 10.0", &format!("max_health = {}", health));
+            assert_invalid(parse(&enemy, TOWER), "max_health must be positive");
+        }
+    }
+
+    #[test]
+    fn rejects_zero_cost() {
+        let tower = TOWER.replace("cost = 10", "cost = 0");
+        assert_invalid(parse(ENEMY, &tower), "cost must be positive");
+
+        let upgrade = r#"
+[[towers.upgrades]]
+cost = 0
+cooldown_ms = 1000
+range = 5.0
+projectile = { speed = 10.0, damage = { value = 1.0, kind = "magic" } }
+"#;
+        let tower = format!("{}{}", TOWER, upgrade);
+        assert_invalid(parse(ENEMY, &tower), "cost must be positive");
+    }
+
+    #[test]
+    fn rejects_fractions_out_of_range() {
+        for (field, value) in [
+            (
+                "effect",
+                "{ kind = \"slow\", factor = 1.5, duration_ms = 100 }",
+            ),
+            ("splash", "{ radius = 2.0, falloff = -0.5 }"),
+        ] {
+            let tower = TOWER.replace(
+                "kind = \"magic\" } }",
+                &format!("kind = \"magic\" }}, {} = {} }}", field, value),
+            );
+            assert_invalid(parse(ENEMY, &tower), "must be within [0, 1]");
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod core;
+pub use core::*;
+
+mod loader;
+pub use loader::*;
@@ -12,7 +12,7 @@ pub struct CameraScaleInvarianceErr {}
 
 impl Display for CameraScaleInvarianceErr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Tried to take money from wallet while not having enough")
+        write!(f, "Tried to zoom camera in past its limit")
     }
 }
 
@@ -52,10 +52,11 @@ impl HandleEvent for Camera {
             }
             InputEvent::CameraScaleUp => {
                 let scale = self.scale();
-                if scale > SCALE_SCROLL {
-                    self.set_scale(scale - SCALE_SCROLL);
+                if scale <= SCALE_SCROLL {
+                    return Err(Box::new(CameraScaleInvarianceErr {}));
                 }
-                Err(Box::new(CameraScaleInvarianceErr {}))
+                self.set_scale(scale - SCALE_SCROLL);
+                Ok(())
             }
             _ => Ok(()),
         }
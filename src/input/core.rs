@@ -1,17 +1,18 @@
 use std::{error::Error, io, time::Duration};
 
-use crossterm::event::{
-    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
-    MouseEventKind,
-};
+use crossterm::event::{self, Event, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    model::{Point, Positioned},
+    model::{Point, Positioned, TowerInfo},
     ui::Camera,
 };
 
-use super::{tower_gap::TowerGap, tower_radius::TowerRadius, tower_selector::TowerSelector};
+use super::{
+    cursor::CursorDirection, keymap::Keymap, tower_gap::TowerGap, tower_radius::TowerRadius,
+    tower_selector::TowerSelector,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct InputContext {
@@ -19,11 +20,16 @@ pub struct InputContext {
     tower_radius: TowerRadius,
     tower_gap: TowerGap,
     screen_info: ScreenInfo,
+    cursor: Point,
+    help_shown: bool,
+    help_scroll: u16,
 }
 
 impl InputContext {
-    pub fn new(min_tower_gap: f32) -> Self {
-        let selector = TowerSelector::default();
+    const HELP_SCROLL_STEP: u16 = 5;
+
+    pub fn new(min_tower_gap: f32, towers: &'static [TowerInfo]) -> Self {
+        let selector = TowerSelector::new(towers);
         Self {
             screen_info: ScreenInfo {
                 camera: Camera::new(Point::default(), 0.0, 0.0),
@@ -33,6 +39,9 @@ impl InputContext {
             tower_selector: selector,
             tower_radius: TowerRadius::new(Point { x: 0.0, y: 0.0 }, selector.current().range),
             tower_gap: TowerGap::new(Point { x: 0.0, y: 0.0 }, min_tower_gap),
+            cursor: Point::default(),
+            help_shown: false,
+            help_scroll: 0,
         }
     }
 
@@ -54,6 +63,30 @@ impl InputContext {
         self
     }
 
+    /// World position towers are placed at, follows both the mouse and cursor keys
+    pub fn cursor(&self) -> Point {
+        self.cursor
+    }
+
+    pub fn set_cursor(&mut self, cursor: Point) -> &mut Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn help_shown(&self) -> bool {
+        self.help_shown
+    }
+
+    /// Lines of the help overlay scrolled past, reset whenever it is opened
+    pub fn help_scroll(&self) -> u16 {
+        self.help_scroll
+    }
+
+    pub fn set_help_scroll(&mut self, help_scroll: u16) -> &mut Self {
+        self.help_scroll = help_scroll;
+        self
+    }
+
     pub fn tower_radius(&self) -> TowerRadius {
         self.tower_radius
     }
@@ -63,6 +96,27 @@ impl InputContext {
     }
 
     pub fn handle(&mut self, event: InputEvent) -> Result<(), Box<dyn Error>> {
+        match event {
+            InputEvent::MouseMovedTo(pos) => self.cursor = pos.to_world_point(self.screen_info),
+            InputEvent::CursorMove(direction) => {
+                self.cursor = direction.move_point(self.cursor, self.screen_info, false)
+            }
+            InputEvent::CursorMoveFast(direction) => {
+                self.cursor = direction.move_point(self.cursor, self.screen_info, true)
+            }
+            InputEvent::HelpSwitch => {
+                self.help_shown = !self.help_shown;
+                self.help_scroll = 0;
+            }
+            InputEvent::HelpScrollUp if self.help_shown => {
+                self.help_scroll = self.help_scroll.saturating_sub(Self::HELP_SCROLL_STEP)
+            }
+            InputEvent::HelpScrollDown if self.help_shown => {
+                self.help_scroll = self.help_scroll.saturating_add(Self::HELP_SCROLL_STEP)
+            }
+            _ => {}
+        }
+
         let mut selector = self.tower_selector;
         selector.handle(event, self)?;
         self.tower_selector = selector;
@@ -79,7 +133,7 @@ impl InputContext {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct ScreenInfo {
     camera: Camera,
     frame_w: u16,
@@ -89,9 +143,9 @@ pub struct ScreenInfo {
 impl ScreenInfo {
     pub fn new(camera: Camera, frame_w: u16, frame_h: u16) -> Self {
         Self {
-            camera: camera,
-            frame_w: frame_w,
-            frame_h: frame_h,
+            camera,
+            frame_w,
+            frame_h,
         }
     }
 
@@ -118,7 +172,9 @@ impl ScreenInfo {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 pub struct MousePos {
     col: u16,
     row: u16,
@@ -140,14 +196,18 @@ impl MousePos {
         let percent_y = 1.0 - self.row as f32 / info.frame_h as f32;
         let len_y = info.len_y() as f32;
         let y = info.camera().position().y + percent_y * len_y;
-        Point { x: x, y: y }
+        Point { x, y }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum InputEvent {
     GameQuit,
+    GameSave,
     GamePauseSwitch,
+    HelpSwitch,
+    HelpScrollUp,
+    HelpScrollDown,
     CameraRight,
     CameraLeft,
     CameraUp,
@@ -158,11 +218,25 @@ pub enum InputEvent {
     MousePressedL(MousePos),
     MousePressedR(MousePos),
     TowerSelectorNext,
+    TowerUpgrade,
+    TowerTargetingNext,
+    TowerDeselect,
+    CursorMove(CursorDirection),
+    CursorMoveFast(CursorDirection),
+    CursorPlace,
+    CursorSell,
+    ReplaySpeedUp,
+    ReplaySlowDown,
+    ReplaySeekForward,
+    ReplaySeekBackward,
+    OtherKey,
+    /// The terminal changed its size
+    Resize,
     None,
     Unknown,
 }
 
-pub fn poll_event(timeout: Duration) -> io::Result<InputEvent> {
+pub fn poll_event(timeout: Duration, keymap: &Keymap) -> io::Result<InputEvent> {
     if !event::poll(timeout)? {
         return Ok(InputEvent::None);
     }
@@ -172,82 +246,14 @@ pub fn poll_event(timeout: Duration) -> io::Result<InputEvent> {
             if key.kind == KeyEventKind::Release {
                 return Ok(InputEvent::None);
             }
-            Ok(match_key(key))
+            Ok(keymap.match_key(key))
         }
         Event::Mouse(mouse) => Ok(match_mouse_kind(mouse)),
+        Event::Resize(_, _) => Ok(InputEvent::Resize),
         _ => Ok(InputEvent::Unknown),
     }
 }
 
-fn match_key(key: KeyEvent) -> InputEvent {
-    match key {
-        KeyEvent {
-            code: KeyCode::Char('q'),
-            modifiers: KeyModifiers::CONTROL,
-            kind: _,
-            state: _,
-        } => InputEvent::GameQuit,
-
-        KeyEvent {
-            code: KeyCode::Char('p'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::GamePauseSwitch,
-
-        KeyEvent {
-            code: KeyCode::Char('d'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraRight,
-
-        KeyEvent {
-            code: KeyCode::Char('a'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraLeft,
-
-        KeyEvent {
-            code: KeyCode::Char('w'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraUp,
-
-        KeyEvent {
-            code: KeyCode::Char('s'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraDown,
-
-        KeyEvent {
-            code: KeyCode::Char('z'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraScaleUp,
-
-        KeyEvent {
-            code: KeyCode::Char('x'),
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::CameraScaleDown,
-
-        KeyEvent {
-            code: KeyCode::Tab,
-            modifiers: _,
-            kind: _,
-            state: _,
-        } => InputEvent::TowerSelectorNext,
-
-        _ => InputEvent::Unknown,
-    }
-}
-
 fn match_mouse_kind(event: MouseEvent) -> InputEvent {
     use MouseEventKind::*;
     match event.kind {
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use super::core::ScreenInfo;
+use crate::model::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum CursorDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl CursorDirection {
+    const FAST_STEPS: f32 = 5.0;
+
+    /// One step is a single terminal cell at the current camera scale
+    pub fn move_point(&self, point: Point, info: ScreenInfo, fast: bool) -> Point {
+        let (frame_w, frame_h) = info.size();
+        let mut step_x = info.len_x() as f32 / frame_w.max(1) as f32;
+        let mut step_y = info.len_y() as f32 / frame_h.max(1) as f32;
+        if fast {
+            step_x *= Self::FAST_STEPS;
+            step_y *= Self::FAST_STEPS;
+        }
+        let (dx, dy) = match self {
+            CursorDirection::Left => (-step_x, 0.0),
+            CursorDirection::Right => (step_x, 0.0),
+            CursorDirection::Up => (0.0, step_y),
+            CursorDirection::Down => (0.0, -step_y),
+        };
+        Point {
+            x: point.x + dx,
+            y: point.y + dy,
+        }
+    }
+}
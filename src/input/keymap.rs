@@ -0,0 +1,631 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+use super::{core::InputEvent, cursor::CursorDirection};
+
+/// Declares every action once: its name in keymap files, the input event it
+/// sends, default keys and the description shown in the help overlay
+macro_rules! actions {
+    ($(
+        $action:ident {
+            name: $name:literal,
+            event: $event:expr,
+            keys: [$($key:literal),+],
+            description: $description:literal,
+        }
+    )+) => {
+        /// Input events which can be bound to keys, named in snake case in keymap files
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+        pub enum Action {
+            $(
+                #[serde(rename = $name)]
+                $action,
+            )+
+        }
+
+        impl Action {
+            pub const ALL: &'static [Action] = &[$(Action::$action),+];
+
+            pub fn event(&self) -> InputEvent {
+                match self {
+                    $(Action::$action => $event,)+
+                }
+            }
+
+            pub fn default_keys(&self) -> &'static [&'static str] {
+                match self {
+                    $(Action::$action => &[$($key),+],)+
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Action::$action => $name,)+
+                }
+            }
+
+            /// Human readable description shown in the help overlay
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(Action::$action => $description,)+
+                }
+            }
+        }
+    };
+}
+
+actions! {
+    GameQuit {
+        name: "game_quit",
+        event: InputEvent::GameQuit,
+        keys: ["ctrl+q"],
+        description: "Quit game",
+    }
+    GameSave {
+        name: "game_save",
+        event: InputEvent::GameSave,
+        keys: ["ctrl+s"],
+        description: "Save game",
+    }
+    GamePauseSwitch {
+        name: "game_pause_switch",
+        event: InputEvent::GamePauseSwitch,
+        keys: ["p"],
+        description: "Pause or resume",
+    }
+    HelpSwitch {
+        name: "help_switch",
+        event: InputEvent::HelpSwitch,
+        keys: ["?"],
+        description: "Show or hide help",
+    }
+    HelpScrollUp {
+        name: "help_scroll_up",
+        event: InputEvent::HelpScrollUp,
+        keys: ["pageup"],
+        description: "Scroll help up",
+    }
+    HelpScrollDown {
+        name: "help_scroll_down",
+        event: InputEvent::HelpScrollDown,
+        keys: ["pagedown"],
+        description: "Scroll help down",
+    }
+    CameraRight {
+        name: "camera_right",
+        event: InputEvent::CameraRight,
+        keys: ["d"],
+        description: "Move camera right",
+    }
+    CameraLeft {
+        name: "camera_left",
+        event: InputEvent::CameraLeft,
+        keys: ["a"],
+        description: "Move camera left",
+    }
+    CameraUp {
+        name: "camera_up",
+        event: InputEvent::CameraUp,
+        keys: ["w"],
+        description: "Move camera up",
+    }
+    CameraDown {
+        name: "camera_down",
+        event: InputEvent::CameraDown,
+        keys: ["s"],
+        description: "Move camera down",
+    }
+    CameraScaleUp {
+        name: "camera_scale_up",
+        event: InputEvent::CameraScaleUp,
+        keys: ["z"],
+        description: "Zoom in",
+    }
+    CameraScaleDown {
+        name: "camera_scale_down",
+        event: InputEvent::CameraScaleDown,
+        keys: ["x"],
+        description: "Zoom out",
+    }
+    TowerSelectorNext {
+        name: "tower_selector_next",
+        event: InputEvent::TowerSelectorNext,
+        keys: ["tab"],
+        description: "Next tower type",
+    }
+    TowerUpgrade {
+        name: "tower_upgrade",
+        event: InputEvent::TowerUpgrade,
+        keys: ["u"],
+        description: "Upgrade selected tower",
+    }
+    TowerTargetingNext {
+        name: "tower_targeting_next",
+        event: InputEvent::TowerTargetingNext,
+        keys: ["t"],
+        description: "Next targeting mode",
+    }
+    TowerDeselect {
+        name: "tower_deselect",
+        event: InputEvent::TowerDeselect,
+        keys: ["esc"],
+        description: "Deselect tower",
+    }
+    CursorLeft {
+        name: "cursor_left",
+        event: InputEvent::CursorMove(CursorDirection::Left),
+        keys: ["left"],
+        description: "Move cursor left",
+    }
+    CursorRight {
+        name: "cursor_right",
+        event: InputEvent::CursorMove(CursorDirection::Right),
+        keys: ["right"],
+        description: "Move cursor right",
+    }
+    CursorUp {
+        name: "cursor_up",
+        event: InputEvent::CursorMove(CursorDirection::Up),
+        keys: ["up"],
+        description: "Move cursor up",
+    }
+    CursorDown {
+        name: "cursor_down",
+        event: InputEvent::CursorMove(CursorDirection::Down),
+        keys: ["down"],
+        description: "Move cursor down",
+    }
+    CursorLeftFast {
+        name: "cursor_left_fast",
+        event: InputEvent::CursorMoveFast(CursorDirection::Left),
+        keys: ["shift+left"],
+        description: "Move cursor left fast",
+    }
+    CursorRightFast {
+        name: "cursor_right_fast",
+        event: InputEvent::CursorMoveFast(CursorDirection::Right),
+        keys: ["shift+right"],
+        description: "Move cursor right fast",
+    }
+    CursorUpFast {
+        name: "cursor_up_fast",
+        event: InputEvent::CursorMoveFast(CursorDirection::Up),
+        keys: ["shift+up"],
+        description: "Move cursor up fast",
+    }
+    CursorDownFast {
+        name: "cursor_down_fast",
+        event: InputEvent::CursorMoveFast(CursorDirection::Down),
+        keys: ["shift+down"],
+        description: "Move cursor down fast",
+    }
+    CursorPlace {
+        name: "cursor_place",
+        event: InputEvent::CursorPlace,
+        keys: ["enter"],
+        description: "Build or select tower at cursor",
+    }
+    CursorSell {
+        name: "cursor_sell",
+        event: InputEvent::CursorSell,
+        keys: ["backspace", "delete"],
+        description: "Sell tower at cursor",
+    }
+    ReplaySpeedUp {
+        name: "replay_speed_up",
+        event: InputEvent::ReplaySpeedUp,
+        keys: ["+", "="],
+        description: "Replay faster",
+    }
+    ReplaySlowDown {
+        name: "replay_slow_down",
+        event: InputEvent::ReplaySlowDown,
+        keys: ["-"],
+        description: "Replay slower",
+    }
+    ReplaySeekForward {
+        name: "replay_seek_forward",
+        event: InputEvent::ReplaySeekForward,
+        keys: ["]"],
+        description: "Replay seek forward",
+    }
+    ReplaySeekBackward {
+        name: "replay_seek_backward",
+        event: InputEvent::ReplaySeekBackward,
+        keys: ["["],
+        description: "Replay seek backward",
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Key with modifiers, written like `ctrl+q`, `shift+up` or `f1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    const NAMED_KEYS: [(&'static str, KeyCode); 17] = [
+        ("tab", KeyCode::Tab),
+        ("backtab", KeyCode::BackTab),
+        ("esc", KeyCode::Esc),
+        ("enter", KeyCode::Enter),
+        ("space", KeyCode::Char(' ')),
+        ("backspace", KeyCode::Backspace),
+        ("delete", KeyCode::Delete),
+        ("insert", KeyCode::Insert),
+        ("home", KeyCode::Home),
+        ("end", KeyCode::End),
+        ("pageup", KeyCode::PageUp),
+        ("pagedown", KeyCode::PageDown),
+        ("up", KeyCode::Up),
+        ("down", KeyCode::Down),
+        ("left", KeyCode::Left),
+        ("right", KeyCode::Right),
+        ("plus", KeyCode::Char('+')),
+    ];
+
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Shift is already part of typed characters, so it is ignored for them
+    pub fn from_key(key: KeyEvent) -> Self {
+        let mut modifiers = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        if !matches!(key.code, KeyCode::Char(_)) {
+            modifiers |= key.modifiers & KeyModifiers::SHIFT;
+        }
+        Self::new(key.code, modifiers)
+    }
+
+    pub fn parse(text: &str) -> Option<Self> {
+        let (modifier_names, key) = match text.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => text.rsplit_once('+').unwrap_or(("", text)),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+            modifiers |= match name.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(char), None) => KeyCode::Char(char),
+            _ => Self::parse_named(&key.to_lowercase())?,
+        };
+        Some(Self::from_key(KeyEvent::new(code, modifiers)))
+    }
+
+    fn parse_named(name: &str) -> Option<KeyCode> {
+        if let Some(number) = name.strip_prefix('f') {
+            return number
+                .parse()
+                .ok()
+                .filter(|number| (1..=12).contains(number))
+                .map(KeyCode::F);
+        }
+        match name {
+            "escape" => Some(KeyCode::Esc),
+            "return" => Some(KeyCode::Enter),
+            "del" => Some(KeyCode::Delete),
+            _ => Self::NAMED_KEYS
+                .iter()
+                .find(|(key_name, _)| *key_name == name)
+                .map(|(_, code)| *code),
+        }
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl"),
+            (KeyModifiers::ALT, "alt"),
+            (KeyModifiers::SHIFT, "shift"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(char) => write!(f, "{}", char),
+            KeyCode::F(number) => write!(f, "f{}", number),
+            code => {
+                let name = Self::NAMED_KEYS
+                    .iter()
+                    .find(|(_, named)| *named == code)
+                    .map_or("?", |(name, _)| name);
+                write!(f, "{}", name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyConflict {
+    pub chord: KeyChord,
+    pub first: Action,
+    pub second: Action,
+}
+
+impl Display for KeyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is bound to both {} and {}",
+            self.chord, self.first, self.second
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum KeymapErr {
+    Read(String, io::Error),
+    Parse(String, toml::de::Error),
+    InvalidKey(Action, String),
+    Conflicts(Vec<KeyConflict>),
+}
+
+impl Display for KeymapErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeymapErr::Read(path, err) => {
+                write!(f, "Failed to read keymap file {}: {}", path, err)
+            }
+            KeymapErr::Parse(path, err) => {
+                write!(f, "Failed to parse keymap file {}: {}", path, err)
+            }
+            KeymapErr::InvalidKey(action, key) => {
+                write!(f, "Unknown key '{}' bound to {}", key, action)
+            }
+            KeymapErr::Conflicts(conflicts) => {
+                write!(f, "Conflicting key bindings:")?;
+                for conflict in conflicts {
+                    write!(f, "\n  {}", conflict)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for KeymapErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeymapErr::Read(_, err) => Some(err),
+            KeymapErr::Parse(_, err) => Some(err),
+            KeymapErr::InvalidKey(_, _) | KeymapErr::Conflicts(_) => None,
+        }
+    }
+}
+
+/// Maps key chords to input events. A chord without modifiers also
+/// matches when pressed with modifiers nothing else is bound to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    actions: BTreeMap<Action, Vec<KeyChord>>,
+    chords: HashMap<KeyChord, Action>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| (*action, Self::default_chords(*action)))
+            .collect();
+        Self::from_bindings(bindings).expect("default key bindings don't conflict")
+    }
+}
+
+impl Keymap {
+    /// Actions left out of the file keep their default keys
+    pub fn load(path: &Path) -> Result<Self, KeymapErr> {
+        let path_str = path.display().to_string();
+        let text =
+            fs::read_to_string(path).map_err(|err| KeymapErr::Read(path_str.clone(), err))?;
+        let raw: BTreeMap<Action, Vec<String>> =
+            toml::from_str(&text).map_err(|err| KeymapErr::Parse(path_str, err))?;
+
+        let mut bindings = Action::ALL
+            .iter()
+            .map(|action| (*action, Self::default_chords(*action)))
+            .collect::<BTreeMap<_, _>>();
+        for (action, keys) in raw {
+            let chords = keys
+                .iter()
+                .map(|key| {
+                    KeyChord::parse(key).ok_or_else(|| KeymapErr::InvalidKey(action, key.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            bindings.insert(action, chords);
+        }
+        Self::from_bindings(bindings)
+    }
+
+    /// `$XDG_CONFIG_HOME/tdtui/keys.toml`, falling back to `~/.config`
+    pub fn default_path() -> Option<PathBuf> {
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("tdtui").join("keys.toml"))
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyChord] {
+        self.actions.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn match_key(&self, key: KeyEvent) -> InputEvent {
+        let chord = KeyChord::from_key(key);
+        self.chords
+            .get(&chord)
+            .or_else(|| {
+                self.chords
+                    .get(&KeyChord::new(chord.code, KeyModifiers::NONE))
+            })
+            .map_or(InputEvent::OtherKey, |action| action.event())
+    }
+
+    fn default_chords(action: Action) -> Vec<KeyChord> {
+        action
+            .default_keys()
+            .iter()
+            .filter_map(|key| KeyChord::parse(key))
+            .collect()
+    }
+
+    fn from_bindings(actions: BTreeMap<Action, Vec<KeyChord>>) -> Result<Self, KeymapErr> {
+        let mut chords = HashMap::new();
+        let mut conflicts = Vec::new();
+        for (action, action_chords) in actions.iter() {
+            for chord in action_chords {
+                match chords.get(chord) {
+                    Some(bound) if bound != action => conflicts.push(KeyConflict {
+                        chord: *chord,
+                        first: *bound,
+                        second: *action,
+                    }),
+                    _ => {
+                        chords.insert(*chord, *action);
+                    }
+                }
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(KeymapErr::Conflicts(conflicts));
+        }
+        Ok(Self { actions, chords })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(code: KeyCode, modifiers: KeyModifiers) -> Option<KeyChord> {
+        Some(KeyChord::new(code, modifiers))
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        assert_eq!(
+            KeyChord::parse("ctrl+q"),
+            chord(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyChord::parse("shift+left"),
+            chord(KeyCode::Left, KeyModifiers::SHIFT)
+        );
+        // Shift is part of typed characters
+        assert_eq!(
+            KeyChord::parse("shift+a"),
+            chord(KeyCode::Char('a'), KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn parse_plus() {
+        assert_eq!(
+            KeyChord::parse("+"),
+            chord(KeyCode::Char('+'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::parse("ctrl++"),
+            chord(KeyCode::Char('+'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(KeyChord::parse("ctrl++"), KeyChord::parse("ctrl+plus"));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_keys() {
+        assert_eq!(
+            KeyChord::parse("f12"),
+            chord(KeyCode::F(12), KeyModifiers::NONE)
+        );
+        for key in ["f13", "f0", "", "ctrl+", "hyper+a", "nokey"] {
+            assert_eq!(KeyChord::parse(key), None, "{:?} should be rejected", key);
+        }
+    }
+
+    #[test]
+    fn duplicate_bindings_conflict() {
+        let q = KeyChord::parse("q").unwrap();
+        let bindings = BTreeMap::from([(Action::GameQuit, vec![q]), (Action::GameSave, vec![q])]);
+        let Err(KeymapErr::Conflicts(conflicts)) = Keymap::from_bindings(bindings) else {
+            panic!("conflict wasn't reported");
+        };
+        assert_eq!(
+            conflicts,
+            vec![KeyConflict {
+                chord: q,
+                first: Action::GameQuit,
+                second: Action::GameSave,
+            }]
+        );
+    }
+
+    #[test]
+    fn conflicts_are_listed_one_per_line() {
+        let q = KeyChord::parse("q").unwrap();
+        let ctrl_s = KeyChord::parse("ctrl+s").unwrap();
+        let bindings = BTreeMap::from([
+            (Action::GameQuit, vec![q]),
+            (Action::GameSave, vec![q, ctrl_s]),
+            (Action::CameraUp, vec![ctrl_s]),
+        ]);
+        let err = Keymap::from_bindings(bindings).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Conflicting key bindings:\n  \
+             'q' is bound to both game_quit and game_save\n  \
+             'ctrl+s' is bound to both game_save and camera_up"
+        );
+    }
+
+    #[test]
+    fn same_key_twice_for_one_action_is_no_conflict() {
+        let q = KeyChord::parse("q").unwrap();
+        let bindings = BTreeMap::from([(Action::GameQuit, vec![q, q])]);
+        assert!(Keymap::from_bindings(bindings).is_ok());
+    }
+
+    #[test]
+    fn default_keys_parse() {
+        for action in Action::ALL {
+            for key in action.default_keys() {
+                assert!(KeyChord::parse(key).is_some(), "{} of {}", key, action);
+            }
+        }
+        Keymap::default();
+    }
+
+    #[test]
+    fn names_match_keymap_file() {
+        for &action in Action::ALL {
+            let text = format!("{} = []", action.name());
+            let raw: BTreeMap<Action, Vec<String>> = toml::from_str(&text).unwrap();
+            assert_eq!(raw.into_keys().collect::<Vec<_>>(), vec![action]);
+        }
+    }
+}
@@ -4,8 +4,13 @@ pub use camera::*;
 mod core;
 pub use core::*;
 
+mod cursor;
+pub use cursor::*;
+
+mod keymap;
+pub use keymap::*;
+
 mod model;
-pub use model::*;
 
 mod tower_gap;
 pub use tower_gap::*;
@@ -1,7 +1,7 @@
 use std::error::Error;
 
 use super::core::{HandleEvent, InputContext, InputEvent};
-use crate::model::{ConcreteGameModel, GameModel, Spawner, Trajectory};
+use crate::model::{ConcreteGameModel, GameModel, Point, Spawner, Trajectory};
 
 impl<S: Spawner, T: Trajectory> HandleEvent for ConcreteGameModel<S, T> {
     fn handle(
@@ -11,14 +11,53 @@ impl<S: Spawner, T: Trajectory> HandleEvent for ConcreteGameModel<S, T> {
     ) -> Result<(), Box<dyn Error>> {
         match event {
             InputEvent::MousePressedL(input) => {
-                self.spawn_tower(
-                    input_context
-                        .tower_selector()
-                        .produce_current(input.to_world_point(input_context.screen_info())),
-                )?;
+                let position = input.to_world_point(input_context.screen_info());
+                self.press_at(position, input_context)
+            }
+            InputEvent::CursorPlace => self.press_at(input_context.cursor(), input_context),
+            InputEvent::MousePressedR(input) => {
+                self.sell_at(input.to_world_point(input_context.screen_info()));
+                Ok(())
+            }
+            InputEvent::CursorSell => {
+                self.sell_at(input_context.cursor());
+                Ok(())
+            }
+            InputEvent::TowerUpgrade => self.upgrade_selected_tower(),
+            InputEvent::TowerTargetingNext => {
+                if let Some(tower) = self.selected_tower_mut() {
+                    tower.set_targeting(tower.targeting().next());
+                }
+                Ok(())
+            }
+            InputEvent::TowerDeselect => {
+                self.select_tower(None);
                 Ok(())
             }
             _ => Ok(()),
         }
     }
 }
+
+impl<S: Spawner, T: Trajectory> ConcreteGameModel<S, T> {
+    /// Selects the tower under the position or builds the current one there
+    fn press_at(
+        &mut self,
+        position: Point,
+        input_context: &InputContext,
+    ) -> Result<(), Box<dyn Error>> {
+        if let Some(idx) = self.tower_at(position) {
+            self.select_tower(Some(idx));
+            return Ok(());
+        }
+        self.select_tower(None);
+        self.spawn_tower(input_context.tower_selector().produce_current(position))?;
+        Ok(())
+    }
+
+    fn sell_at(&mut self, position: Point) {
+        if let Some(idx) = self.tower_at(position) {
+            self.sell_tower(idx);
+        }
+    }
+}
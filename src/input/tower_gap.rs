@@ -12,7 +12,7 @@ pub struct TowerGap {
 impl TowerGap {
     pub fn new(position: Point, radius: f32) -> Self {
         Self {
-            position: position,
+            position,
             range: radius,
         }
     }
@@ -35,8 +35,10 @@ impl HandleEvent for TowerGap {
         input_context: &InputContext,
     ) -> Result<(), Box<dyn Error>> {
         match event {
-            InputEvent::MouseMovedTo(pos) => {
-                self.position = pos.to_world_point(input_context.screen_info());
+            InputEvent::MouseMovedTo(_)
+            | InputEvent::CursorMove(_)
+            | InputEvent::CursorMoveFast(_) => {
+                self.position = input_context.cursor();
                 Ok(())
             }
             _ => Ok(()),
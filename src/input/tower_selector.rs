@@ -2,32 +2,33 @@ use std::error::Error;
 
 use super::core::{HandleEvent, InputContext, InputEvent};
 
-use crate::{
-    assets::tower::{ARCHER_TOWER_INFO, MAGE_TOWER_INFO},
-    model::{
-        Point, {Tower, TowerInfo},
-    },
+use crate::model::{
+    Point, {Tower, TowerInfo},
 };
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub struct TowerSelector {
+    opts: &'static [TowerInfo],
     idx: usize,
 }
 
 impl TowerSelector {
-    const OPTS: &[TowerInfo] = &[ARCHER_TOWER_INFO, MAGE_TOWER_INFO];
+    pub fn new(opts: &'static [TowerInfo]) -> Self {
+        assert!(!opts.is_empty());
+        Self { opts, idx: 0 }
+    }
 
     pub fn produce_current(&self, position: Point) -> Tower {
-        Tower::new(position, &Self::OPTS[self.idx])
+        Tower::new(position, self.current())
     }
 
     pub fn current(&self) -> &'static TowerInfo {
-        &Self::OPTS[self.idx]
+        &self.opts[self.idx]
     }
 
     pub fn to_next(&mut self) {
         self.idx += 1;
-        self.idx %= Self::OPTS.len();
+        self.idx %= self.opts.len();
     }
 }
 
@@ -1,32 +1,182 @@
 pub mod app;
 pub mod assets;
+pub mod cli;
+pub mod config;
+pub mod content;
 pub mod input;
+pub mod menu;
 pub mod model;
+pub mod replay;
+pub mod save;
+pub mod sim;
 pub mod ui;
 
-use std::{error::Error, time::Duration};
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-use rand::Rng;
+use clap::Parser;
 
-use app::App;
-use model::{ConcreteGameModel, NoiseTrajectory, Point, RandomizedSpawnerWithCooldown};
+use app::{App, Launcher};
+use cli::Cli;
+use config::{Game, GameConfig};
+use content::Content;
+use input::Keymap;
+use model::Point;
+use replay::{Replay, ReplayPlayer};
+use save::SaveFile;
+use sim::{write_reports, SimScript};
 use ui::{Camera, Screen};
 
-use noise::Perlin;
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let target_fps = 60;
-    let tick_duration = Duration::from_millis(1000) / target_fps;
+fn main() -> ExitCode {
+    // Errors are printed with Display, the default report would use Debug
+    if let Err(err) = run() {
+        eprintln!("Error: {}", err);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
 
-    let perlin = Perlin::new(rand::thread_rng().gen());
-    let spawner = RandomizedSpawnerWithCooldown::new(Duration::from_secs_f32(1.0));
-    let trajectory = NoiseTrajectory::new(&perlin);
-    let model = ConcreteGameModel::new(spawner, trajectory, 1000, 1.0);
+fn run() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    if let Some(path) = &cli.simulate {
+        return simulate(&cli, path);
+    }
 
-    let camera = Camera::new(Point::default(), 0.3, 1.0);
+    let keymap = load_keymap(cli.keys.as_deref())?;
+    let camera = Camera::new(Point::default(), 0.3, cli.scale);
     let ui = Screen::new()?;
 
-    let mut app = App::new(model, ui, camera);
-    app.run(tick_duration)?;
+    if let Some(path) = &cli.replay {
+        let replay = Replay::load(path)?;
+        replay.check_content()?;
+        let content = load_content(replay.content())?;
+        let config = replay.config();
+
+        let player = ReplayPlayer::new(replay);
+        let make_model = move || Ok(config.build_model(content)?);
+        let mut app = App::replay(player, make_model, ui, camera)?;
+        app.set_keymap(keymap);
+        app.run(cli.tick_duration())?;
+        return Ok(());
+    }
+
+    let mut launcher = GameFiles::new(cli.content.clone(), cli.save.clone())?;
+    let config = cli.game_config();
+    let mut app = match &cli.load {
+        Some(path) => App::new(launcher.load_from(path)?, ui, camera),
+        None => {
+            // The game set up on the command line is shown behind the main menu
+            let mut app = App::new(launcher.new_game(config)?, ui, camera);
+            app.open_menu();
+            if let Some(path) = &cli.record {
+                let content_hash = cli.content.as_deref().map(Content::file_hash).transpose()?;
+                app.set_recording(cli.content.clone(), content_hash, path.clone());
+            }
+            app
+        }
+    };
+
+    app.set_keymap(keymap)
+        .set_build_phase(cli.build_phase)
+        .set_new_game_config(config)
+        .set_launcher(launcher);
+    app.run(cli.tick_duration())?;
     Ok(())
 }
+
+/// Content given on the command line is used for new games, loaded games
+/// use the content they were saved with
+struct GameFiles {
+    content_path: Option<PathBuf>,
+    content: Content,
+    save_path: PathBuf,
+    game_content_path: Option<PathBuf>,
+}
+
+impl GameFiles {
+    fn new(content_path: Option<PathBuf>, save_path: PathBuf) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            content: load_content(content_path.as_deref())?,
+            game_content_path: content_path.clone(),
+            content_path,
+            save_path,
+        })
+    }
+
+    fn load_from(&mut self, path: &Path) -> Result<Game, Box<dyn Error>> {
+        let save = SaveFile::load(path)?;
+        let content_path = save.content().map(Path::to_path_buf);
+        let content = if content_path == self.content_path {
+            self.content
+        } else {
+            load_content(content_path.as_deref())?
+        };
+        let model = save.restore(content)?;
+        self.game_content_path = content_path;
+        Ok(model)
+    }
+}
+
+impl Launcher<Game> for GameFiles {
+    fn new_game(&mut self, config: GameConfig) -> Result<Game, Box<dyn Error>> {
+        self.game_content_path = self.content_path.clone();
+        Ok(config.build_model(self.content)?)
+    }
+
+    fn load_game(&mut self) -> Result<Game, Box<dyn Error>> {
+        let path = self.save_path.clone();
+        self.load_from(&path)
+    }
+
+    fn save_game(&self, game: &Game) -> Result<(), Box<dyn Error>> {
+        SaveFile::new(self.game_content_path.clone(), game)?.save(&self.save_path)?;
+        Ok(())
+    }
+}
+
+fn simulate(cli: &Cli, script: &Path) -> Result<(), Box<dyn Error>> {
+    let content = load_content(cli.content.as_deref())?;
+    let script = SimScript::load(script, content)?;
+    let config = cli.game_config();
+
+    let reports = (0..cli.runs)
+        .map(|run| {
+            let seed = config.seed.wrapping_add(run);
+            script.run(GameConfig { seed, ..config }, content)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match &cli.output {
+        Some(path) => write_reports(
+            &reports,
+            cli.format,
+            content,
+            BufWriter::new(File::create(path)?),
+        )?,
+        None => write_reports(&reports, cli.format, content, io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+/// A missing file at the default location just means no custom bindings
+fn load_keymap(path: Option<&Path>) -> Result<Keymap, Box<dyn Error>> {
+    if let Some(path) = path {
+        return Ok(Keymap::load(path)?);
+    }
+    match Keymap::default_path() {
+        Some(path) if path.exists() => Ok(Keymap::load(&path)?),
+        _ => Ok(Keymap::default()),
+    }
+}
+
+fn load_content(path: Option<&Path>) -> Result<Content, Box<dyn Error>> {
+    Ok(match path {
+        Some(path) => Content::load(path)?,
+        None => Content::builtin(),
+    })
+}
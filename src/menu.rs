@@ -0,0 +1,397 @@
+use std::{fmt::Display, time::Duration};
+
+use clap::ValueEnum;
+
+use crate::{
+    config::GameConfig,
+    input::{CursorDirection, InputEvent},
+};
+
+/// Menus are driven by the cursor keys, so they follow the player's key bindings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MenuInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+}
+
+impl MenuInput {
+    pub fn from_event(event: InputEvent) -> Option<Self> {
+        match event {
+            InputEvent::CursorMove(direction) | InputEvent::CursorMoveFast(direction) => {
+                Some(match direction {
+                    CursorDirection::Up => MenuInput::Up,
+                    CursorDirection::Down => MenuInput::Down,
+                    CursorDirection::Left => MenuInput::Left,
+                    CursorDirection::Right => MenuInput::Right,
+                })
+            }
+            InputEvent::CursorPlace => Some(MenuInput::Select),
+            InputEvent::TowerDeselect => Some(MenuInput::Back),
+            _ => None,
+        }
+    }
+}
+
+/// Vertical list of items with one of them selected
+#[derive(Debug, Clone, Copy)]
+pub struct Menu<T: 'static> {
+    title: &'static str,
+    items: &'static [T],
+    selected: usize,
+}
+
+impl<T: Copy> Menu<T> {
+    pub fn new(title: &'static str, items: &'static [T]) -> Self {
+        assert!(!items.is_empty());
+        Self {
+            title,
+            items,
+            selected: 0,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn items(&self) -> &'static [T] {
+        self.items
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> T {
+        self.items[self.selected]
+    }
+
+    /// Returns the item chosen with `Select`
+    pub fn handle(&mut self, input: MenuInput) -> Option<T> {
+        let len = self.items.len();
+        match input {
+            MenuInput::Up => self.selected = (self.selected + len - 1) % len,
+            MenuInput::Down => self.selected = (self.selected + 1) % len,
+            MenuInput::Select => return Some(self.selected()),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MainMenuItem {
+    NewGame,
+    Load,
+    Settings,
+    Quit,
+}
+
+impl MainMenuItem {
+    pub const ALL: [MainMenuItem; 4] = [
+        MainMenuItem::NewGame,
+        MainMenuItem::Load,
+        MainMenuItem::Settings,
+        MainMenuItem::Quit,
+    ];
+}
+
+impl Display for MainMenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            MainMenuItem::NewGame => "New game",
+            MainMenuItem::Load => "Load game",
+            MainMenuItem::Settings => "Settings",
+            MainMenuItem::Quit => "Quit",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PauseMenuItem {
+    Resume,
+    Plan,
+    Restart,
+    Save,
+    QuitToMenu,
+}
+
+impl PauseMenuItem {
+    pub const ALL: [PauseMenuItem; 5] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Plan,
+        PauseMenuItem::Restart,
+        PauseMenuItem::Save,
+        PauseMenuItem::QuitToMenu,
+    ];
+    /// Loaded games don't know the setup they were started with
+    pub const WITHOUT_RESTART: [PauseMenuItem; 4] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Plan,
+        PauseMenuItem::Save,
+        PauseMenuItem::QuitToMenu,
+    ];
+}
+
+impl Display for PauseMenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            PauseMenuItem::Resume => "Resume",
+            PauseMenuItem::Plan => "Plan while paused",
+            PauseMenuItem::Restart => "Restart",
+            PauseMenuItem::Save => "Save game",
+            PauseMenuItem::QuitToMenu => "Quit to menu",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SetupRow {
+    Road,
+    Spawner,
+    Difficulty,
+    Seed,
+    Start,
+}
+
+impl SetupRow {
+    pub const ALL: [SetupRow; 5] = [
+        SetupRow::Road,
+        SetupRow::Spawner,
+        SetupRow::Difficulty,
+        SetupRow::Seed,
+        SetupRow::Start,
+    ];
+}
+
+impl Display for SetupRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            SetupRow::Road => "Road",
+            SetupRow::Spawner => "Spawner",
+            SetupRow::Difficulty => "Difficulty",
+            SetupRow::Seed => "Seed",
+            SetupRow::Start => "Start",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum SetupAction {
+    Start(GameConfig),
+    Back,
+}
+
+/// New game screen, options not shown here are kept from the command line
+#[derive(Debug, Clone, Copy)]
+pub struct GameSetup {
+    config: GameConfig,
+    rows: Menu<SetupRow>,
+}
+
+impl GameSetup {
+    pub fn new(config: GameConfig) -> Self {
+        Self {
+            config,
+            rows: Menu::new("New game", &SetupRow::ALL),
+        }
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.config
+    }
+
+    pub fn rows(&self) -> &Menu<SetupRow> {
+        &self.rows
+    }
+
+    pub fn value(&self, row: SetupRow) -> Option<String> {
+        match row {
+            SetupRow::Road => value_name(&self.config.road),
+            SetupRow::Spawner => value_name(&self.config.spawner),
+            SetupRow::Difficulty => value_name(&self.config.difficulty),
+            SetupRow::Seed => Some(self.config.seed.to_string()),
+            SetupRow::Start => None,
+        }
+    }
+
+    pub fn handle(&mut self, input: MenuInput) -> Option<SetupAction> {
+        let row = self.rows.selected();
+        match input {
+            MenuInput::Back => return Some(SetupAction::Back),
+            MenuInput::Left => self.change(row, false),
+            MenuInput::Right => self.change(row, true),
+            MenuInput::Select => match row {
+                SetupRow::Start => return Some(SetupAction::Start(self.config)),
+                SetupRow::Seed => self.config.seed = rand::random(),
+                _ => self.change(row, true),
+            },
+            MenuInput::Up | MenuInput::Down => {
+                self.rows.handle(input);
+            }
+        }
+        None
+    }
+
+    fn change(&mut self, row: SetupRow, forward: bool) {
+        let config = &mut self.config;
+        match row {
+            SetupRow::Road => config.road = cycle(&config.road, forward),
+            SetupRow::Spawner => config.spawner = cycle(&config.spawner, forward),
+            SetupRow::Difficulty => config.difficulty = cycle(&config.difficulty, forward),
+            SetupRow::Seed if forward => config.seed = config.seed.wrapping_add(1),
+            SetupRow::Seed => config.seed = config.seed.wrapping_sub(1),
+            SetupRow::Start => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SettingsRow {
+    Zoom,
+    FrameRate,
+    BuildPhase,
+    Back,
+}
+
+impl SettingsRow {
+    pub const ALL: [SettingsRow; 4] = [
+        SettingsRow::Zoom,
+        SettingsRow::FrameRate,
+        SettingsRow::BuildPhase,
+        SettingsRow::Back,
+    ];
+}
+
+impl Display for SettingsRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            SettingsRow::Zoom => "Camera scale",
+            SettingsRow::FrameRate => "Frame rate",
+            SettingsRow::BuildPhase => "Build while paused",
+            SettingsRow::Back => "Back",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Display and pause settings, applied right away
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    scale: f32,
+    fps: u32,
+    build_phase: bool,
+    rows: Menu<SettingsRow>,
+}
+
+impl Settings {
+    const SCALE_STEP: f32 = 0.1;
+    const MIN_SCALE: f32 = 0.2;
+    const MAX_SCALE: f32 = 4.0;
+    const FRAME_RATES: [u32; 5] = [15, 30, 60, 120, 144];
+
+    pub fn new(scale: f32, tick_duration: Duration, build_phase: bool) -> Self {
+        Self {
+            scale,
+            fps: (1.0 / tick_duration.as_secs_f32()).round() as u32,
+            build_phase,
+            rows: Menu::new("Settings", &SettingsRow::ALL),
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn tick_duration(&self) -> Duration {
+        Duration::from_millis(1000) / self.fps.max(1)
+    }
+
+    /// Whether towers can be built, sold and upgraded while the game is paused
+    pub fn build_phase(&self) -> bool {
+        self.build_phase
+    }
+
+    pub fn rows(&self) -> &Menu<SettingsRow> {
+        &self.rows
+    }
+
+    pub fn value(&self, row: SettingsRow) -> Option<String> {
+        match row {
+            SettingsRow::Zoom => Some(format!("{:.1}", self.scale)),
+            SettingsRow::FrameRate => Some(format!("{} fps", self.fps)),
+            SettingsRow::BuildPhase if self.build_phase => Some("on".to_string()),
+            SettingsRow::BuildPhase => Some("off".to_string()),
+            SettingsRow::Back => None,
+        }
+    }
+
+    /// Returns whether the settings screen should be closed
+    pub fn handle(&mut self, input: MenuInput) -> bool {
+        let row = self.rows.selected();
+        match input {
+            MenuInput::Back => return true,
+            MenuInput::Select if row == SettingsRow::Back => return true,
+            MenuInput::Left => self.change(row, false),
+            MenuInput::Right | MenuInput::Select => self.change(row, true),
+            MenuInput::Up | MenuInput::Down => {
+                self.rows.handle(input);
+            }
+        }
+        false
+    }
+
+    fn change(&mut self, row: SettingsRow, forward: bool) {
+        match row {
+            SettingsRow::Zoom => {
+                let step = if forward {
+                    Self::SCALE_STEP
+                } else {
+                    -Self::SCALE_STEP
+                };
+                self.scale = (self.scale + step).clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+            }
+            SettingsRow::FrameRate => {
+                let next = if forward {
+                    Self::FRAME_RATES.into_iter().find(|&fps| fps > self.fps)
+                } else {
+                    Self::FRAME_RATES
+                        .into_iter()
+                        .rev()
+                        .find(|&fps| fps < self.fps)
+                };
+                self.fps = next.unwrap_or(self.fps);
+            }
+            SettingsRow::BuildPhase => self.build_phase = !self.build_phase,
+            SettingsRow::Back => {}
+        }
+    }
+}
+
+fn value_name(value: &impl ValueEnum) -> Option<String> {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+}
+
+fn cycle<T: ValueEnum + PartialEq>(value: &T, forward: bool) -> T {
+    let variants = T::value_variants();
+    let idx = variants
+        .iter()
+        .position(|variant| variant == value)
+        .unwrap_or(0);
+    let len = variants.len();
+    let next = if forward {
+        (idx + 1) % len
+    } else {
+        (idx + len - 1) % len
+    };
+    variants[next].clone()
+}
@@ -0,0 +1,335 @@
+use std::{iter::Enumerate, slice};
+
+use serde::{Deserialize, Serialize};
+
+/// Handle to a value in an `Arena`. A slot is reused only with a new
+/// generation, so handles to removed values never resolve to newer ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EntityId {
+    index: u32,
+    generation: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+/// Saved games are checked on load, the free list must name exactly the
+/// empty slots and the length is counted again
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawArena<T>")]
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    #[serde(skip_serializing)]
+    len: usize,
+}
+
+#[derive(Deserialize)]
+struct RawArena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+}
+
+impl<T> TryFrom<RawArena<T>> for Arena<T> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawArena<T>) -> Result<Self, Self::Error> {
+        let mut listed = vec![false; raw.slots.len()];
+        for &index in &raw.free {
+            match raw.slots.get(index as usize) {
+                None => return Err("arena free list points past its slots"),
+                Some(slot) if slot.value.is_some() => {
+                    return Err("arena free list contains an occupied slot")
+                }
+                Some(_) if listed[index as usize] => {
+                    return Err("arena free list contains a slot twice")
+                }
+                Some(_) => listed[index as usize] = true,
+            }
+        }
+        let len = raw.slots.iter().filter(|slot| slot.value.is_some()).count();
+        if raw.free.len() + len != raw.slots.len() {
+            return Err("arena free list misses empty slots");
+        }
+        Ok(Self {
+            slots: raw.slots,
+            free: raw.free,
+            len,
+        })
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, value: T) -> EntityId {
+        self.insert_with(|_| value)
+    }
+
+    /// Inserts a value which needs to know its own id
+    pub fn insert_with(&mut self, make: impl FnOnce(EntityId) -> T) -> EntityId {
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.generation = slot.generation.wrapping_add(1);
+                let id = EntityId {
+                    index,
+                    generation: slot.generation,
+                };
+                slot.value = Some(make(id));
+                id
+            }
+            None => {
+                let id = EntityId {
+                    index: self.slots.len() as u32,
+                    generation: 0,
+                };
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: Some(make(id)),
+                });
+                id
+            }
+        }
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        self.slots
+            .get(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.slots
+            .get_mut(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let slot = self
+            .slots
+            .get_mut(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)?;
+        let value = slot.value.take()?;
+        self.free.push(id.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(EntityId, &mut T) -> bool) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            let id = EntityId {
+                index: index as u32,
+                generation: slot.generation,
+            };
+            if let Some(value) = slot.value.as_mut() {
+                if !keep(id, value) {
+                    slot.value = None;
+                    self.free.push(id.index);
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> ArenaIter<'_, T> {
+        ArenaIter {
+            slots: self.slots.iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| {
+                let id = EntityId {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                slot.value.as_mut().map(|value| (id, value))
+            })
+    }
+
+    /// Converts every value while keeping ids valid
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Arena<U> {
+        Arena {
+            slots: self
+                .slots
+                .iter()
+                .map(|slot| Slot {
+                    generation: slot.generation,
+                    value: slot.value.as_ref().map(&mut f),
+                })
+                .collect(),
+            free: self.free.clone(),
+            len: self.len,
+        }
+    }
+
+    pub fn try_map<U, E>(
+        self,
+        mut f: impl FnMut(EntityId, T) -> Result<U, E>,
+    ) -> Result<Arena<U>, E> {
+        let slots = self
+            .slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, slot)| {
+                let id = EntityId {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                Ok(Slot {
+                    generation: slot.generation,
+                    value: slot.value.map(|value| f(id, value)).transpose()?,
+                })
+            })
+            .collect::<Result<_, E>>()?;
+        Ok(Arena {
+            slots,
+            free: self.free,
+            len: self.len,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArenaIter<'a, T> {
+    slots: Enumerate<slice::Iter<'a, Slot<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = (EntityId, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if let Some(value) = slot.value.as_ref() {
+                self.remaining -= 1;
+                let id = EntityId {
+                    index: index as u32,
+                    generation: slot.generation,
+                };
+                return Some((id, value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ArenaIter<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(arena: &Arena<&'static str>) -> Vec<&'static str> {
+        arena.iter().map(|(_, &value)| value).collect()
+    }
+
+    #[test]
+    fn reused_slot_gets_new_generation() {
+        let mut arena = Arena::new();
+        let first = arena.insert("a");
+        assert_eq!(arena.remove(first), Some("a"));
+        let second = arena.insert("b");
+
+        assert_eq!(second.index, first.index);
+        assert_eq!(second.generation, first.generation + 1);
+        assert_eq!(arena.get(second), Some(&"b"));
+    }
+
+    #[test]
+    fn stale_ids_resolve_to_nothing() {
+        let mut arena = Arena::new();
+        let stale = arena.insert("a");
+        arena.remove(stale);
+        arena.insert("b");
+
+        assert_eq!(arena.get(stale), None);
+        assert_eq!(arena.get_mut(stale), None);
+        assert_eq!(arena.remove(stale), None);
+        assert_eq!(arena.len(), 1);
+    }
+
+    #[test]
+    fn retain_frees_removed_slots() {
+        let mut arena = Arena::new();
+        let ids = ["a", "b", "c", "d"].map(|value| arena.insert(value));
+        arena.retain(|_, value| *value != "b" && *value != "d");
+
+        assert_eq!(values(&arena), ["a", "c"]);
+        assert_eq!(arena.len(), 2);
+        assert_eq!(arena.get(ids[1]), None);
+        // The slot freed last is reused first
+        assert_eq!(arena.insert("e").index, ids[3].index);
+    }
+
+    #[test]
+    fn iterates_in_slot_order() {
+        let mut arena = Arena::new();
+        let a = arena.insert("a");
+        arena.insert("b");
+        arena.insert("c");
+        arena.remove(a);
+        arena.insert("d");
+
+        assert_eq!(values(&arena), ["d", "b", "c"]);
+        assert_eq!(arena.iter().len(), 3);
+    }
+
+    #[test]
+    fn load_checks_free_list_and_counts_values() {
+        let mut arena = Arena::new();
+        let a = arena.insert("a");
+        arena.insert("b");
+        arena.remove(a);
+        let json = serde_json::to_string(&arena).unwrap();
+        let loaded: Arena<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.len(), 1);
+
+        for free in ["[5]", "[1]", "[0, 0]", "[]"] {
+            let json = format!(
+                r#"{{"slots":[{{"generation":1,"value":null}},{{"generation":0,"value":"b"}}],"free":{}}}"#,
+                free
+            );
+            assert!(
+                serde_json::from_str::<Arena<String>>(&json).is_err(),
+                "{}",
+                free
+            );
+        }
+    }
+}
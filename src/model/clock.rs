@@ -20,3 +20,65 @@ impl Clock {
         self.last_tick = Instant::now();
     }
 }
+
+/// Game logic always advances in steps of this length, which keeps runs reproducible
+pub const SIMULATION_STEP: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedTimestep {
+    step: Duration,
+    accumulator: Duration,
+    max_steps: u32,
+}
+
+impl FixedTimestep {
+    pub fn new(step: Duration, max_steps: u32) -> Self {
+        assert!(!step.is_zero());
+        Self {
+            step,
+            accumulator: Duration::from_millis(0),
+            max_steps,
+        }
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        self.accumulator += elapsed;
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        if steps == self.max_steps {
+            self.accumulator = self.accumulator.min(self.step);
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEP: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn leftover_time_carries_over() {
+        let mut timestep = FixedTimestep::new(STEP, 30);
+        assert_eq!(timestep.advance(Duration::from_millis(25)), 2);
+        assert_eq!(timestep.advance(Duration::from_millis(4)), 0);
+        assert_eq!(timestep.advance(Duration::from_millis(1)), 1);
+    }
+
+    #[test]
+    fn long_frame_is_capped_and_backlog_dropped() {
+        let mut timestep = FixedTimestep::new(STEP, 3);
+        assert_eq!(timestep.advance(Duration::from_millis(1000)), 3);
+        // At most one step is kept, the rest of the frame is never simulated
+        assert_eq!(timestep.advance(Duration::from_millis(0)), 1);
+        assert_eq!(timestep.advance(Duration::from_millis(0)), 0);
+    }
+}
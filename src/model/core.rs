@@ -1,34 +1,125 @@
-use std::{cell::RefCell, error::Error, fmt::Display, rc::Rc, time::Duration};
+use std::{error::Error, fmt::Display, time::Duration};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::content::Content;
 
 use super::{
-    enemy::Enemy,
-    point::Positioned,
-    spawner::Spawner,
-    tower::{Projectile, Tower},
-    trajectory::Trajectory,
+    arena::{Arena, ArenaIter, EntityId},
+    economy::{Interest, Ledger},
+    enemy::{Enemy, EnemyInfo},
+    event::GameEvent,
+    explosion::Explosion,
+    lives::Lives,
+    placement::{MapBounds, PlacementErr},
+    point::{Point, Positioned},
+    snapshot::{GameSnapshot, SnapshotErr},
+    spawner::{Spawner, WaveStatus},
+    stats::{GameOutcome, GameStats},
+    tower::{MaxTowerLevelErr, Projectile, Tower},
+    trajectory::{Polyline, Trajectory},
     wallet::Wallet,
 };
 
-pub type EnemyShared = Rc<RefCell<Enemy>>;
-pub type EnemyUnique = Box<Enemy>;
+pub type EnemyId = EntityId;
+pub type GameRng = ChaCha12Rng;
+
+/// Game model setting outside of its allowed range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingErr {
+    SellRefundRatio(f32),
+    RoadClearance(f32),
+    Bounds(MapBounds),
+}
+
+impl Display for SettingErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingErr::SellRefundRatio(ratio) => {
+                write!(
+                    f,
+                    "Sell refund ratio must be between 0 and 1, got {}",
+                    ratio
+                )
+            }
+            SettingErr::RoadClearance(clearance) => write!(
+                f,
+                "Road clearance must be a non-negative distance, got {}",
+                clearance
+            ),
+            SettingErr::Bounds(bounds) => write!(
+                f,
+                "Map bounds must be finite with the minimum below the maximum, got ({}, {}) to ({}, {})",
+                bounds.min.x, bounds.min.y, bounds.max.x, bounds.max.y
+            ),
+        }
+    }
+}
+
+impl Error for SettingErr {}
 
 pub trait GameModel {
     fn update(&mut self, delta_time: Duration);
 
-    fn is_over(&self) -> bool;
+    fn content(&self) -> Content;
+    fn seed(&self) -> u64;
+    fn rng(&mut self) -> &mut GameRng;
+
+    fn outcome(&self) -> Option<GameOutcome>;
+    fn quit(&mut self);
+    fn is_over(&self) -> bool {
+        self.outcome().is_some()
+    }
+    fn wave_status(&self) -> Option<WaveStatus>;
     fn trajectory(&self) -> &dyn Trajectory;
 
-    fn enemies(&self) -> &Vec<EnemyShared>;
+    fn enemies(&self) -> ArenaIter<'_, Enemy>;
+    fn enemy(&self, id: EnemyId) -> Option<&Enemy>;
+    fn enemy_mut(&mut self, id: EnemyId) -> Option<&mut Enemy>;
     fn towers(&self) -> &Vec<Tower>;
     fn projectiles(&self) -> &Vec<Projectile>;
+    fn explosions(&self) -> &Vec<Explosion>;
 
     fn spawn_projectile(&mut self, projectile: Projectile);
+    fn spawn_explosion(&mut self, explosion: Explosion);
     fn spawn_tower(&mut self, tower: Tower) -> Result<(), Box<dyn Error>>;
-    fn spawn_enemy(&mut self, enemy: Enemy);
+    /// Checks whether a tower could be built at the position, ignoring its cost
+    fn check_placement(&self, position: Point) -> Result<(), PlacementErr>;
+    fn spawn_enemy(&mut self, type_info: &'static EnemyInfo) -> EnemyId;
+
+    fn tower_at(&self, position: Point) -> Option<usize>;
+    fn selected_tower(&self) -> Option<&Tower>;
+    fn selected_tower_mut(&mut self) -> Option<&mut Tower>;
+    fn select_tower(&mut self, idx: Option<usize>);
+    fn upgrade_selected_tower(&mut self) -> Result<(), Box<dyn Error>>;
+    fn sell_tower(&mut self, idx: usize) -> Option<u64>;
 
     fn wallet(&self) -> Wallet;
     fn wallet_mut(&mut self) -> &mut Wallet;
 
+    fn lives(&self) -> Lives;
+
+    fn stats(&self) -> &GameStats;
+    fn ledger(&self) -> &Ledger;
+
+    /// Queues an event until it is drained, statistics see it right away
+    fn emit(&mut self, event: GameEvent);
+    /// Events emitted since the last drain, in order
+    fn drain_events(&mut self) -> Vec<GameEvent>;
+
+    fn reward_kill(&mut self, id: EnemyId, enemy: &'static EnemyInfo) {
+        self.wallet_mut().add_money(enemy.reward);
+        self.emit(GameEvent::EnemyKilled {
+            id,
+            enemy,
+            reward: enemy.reward,
+        });
+    }
+
+    /// Pays the wave completion bonus and interest on the banked gold
+    fn reward_wave(&mut self, wave: usize, bonus: u64);
+
     fn min_tower_gap(&self) -> f32;
 }
 
@@ -37,56 +128,222 @@ pub trait UpdatableObject {
 }
 
 pub struct ConcreteGameModel<S: Spawner, T: Trajectory> {
+    content: Content,
+    seed: u64,
+    rng: GameRng,
     min_tower_gap: f32,
+    road_clearance: f32,
+    bounds: MapBounds,
+    sell_refund_ratio: f32,
+    interest: Option<Interest>,
     trajectory: T,
+    road: Polyline,
     spawner: S,
     towers: Vec<Tower>,
-    enemies: Vec<EnemyShared>,
+    selected_tower: Option<usize>,
+    enemies: Arena<Enemy>,
     projectiles: Vec<Projectile>,
+    explosions: Vec<Explosion>,
     player_wallet: Wallet,
+    player_lives: Lives,
+    stats: GameStats,
+    ledger: Ledger,
+    events: Vec<GameEvent>,
+    outcome: Option<GameOutcome>,
 }
 
 impl<S: Spawner, T: Trajectory> ConcreteGameModel<S, T> {
     const ROAD_LEN: f32 = 100.0;
-
-    pub fn new(spawner: S, trajectory: T, initial_balance: u64, min_tower_gap: f32) -> Self {
+    const DEFAULT_SELL_REFUND_RATIO: f32 = 0.5;
+    const DEFAULT_ROAD_CLEARANCE: f32 = 1.0;
+    const DEFAULT_BOUNDS: MapBounds = MapBounds {
+        min: Point { x: 0.0, y: -20.0 },
+        max: Point {
+            x: Self::ROAD_LEN,
+            y: 20.0,
+        },
+    };
+
+    pub fn new(
+        content: Content,
+        seed: u64,
+        spawner: S,
+        make_trajectory: impl FnOnce(&mut GameRng) -> T,
+        initial_balance: u64,
+        initial_lives: u64,
+        min_tower_gap: f32,
+    ) -> Self {
         let mut wallet = Wallet::default();
         wallet.add_money(initial_balance);
 
+        let mut rng = GameRng::seed_from_u64(seed);
+        let trajectory = make_trajectory(&mut rng);
+        let road = Polyline::sample(&trajectory, Self::ROAD_LEN);
+
         Self {
+            content,
+            seed,
+            rng,
             min_tower_gap,
+            road_clearance: Self::DEFAULT_ROAD_CLEARANCE,
+            bounds: Self::DEFAULT_BOUNDS,
+            sell_refund_ratio: Self::DEFAULT_SELL_REFUND_RATIO,
+            interest: None,
             towers: Vec::new(),
-            enemies: Vec::new(),
+            selected_tower: None,
+            enemies: Arena::new(),
             projectiles: Vec::new(),
+            explosions: Vec::new(),
             player_wallet: wallet,
-            spawner: spawner,
-            trajectory: trajectory,
+            player_lives: Lives::new(initial_lives),
+            stats: GameStats::default(),
+            ledger: Ledger::default(),
+            events: Vec::new(),
+            outcome: None,
+            spawner,
+            trajectory,
+            road,
         }
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct TooTightTowerPlacementErr {}
+    pub fn sell_refund_ratio(&self) -> f32 {
+        self.sell_refund_ratio
+    }
 
-impl Display for TooTightTowerPlacementErr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Tried to build tower too close to other tower")
+    pub fn set_sell_refund_ratio(&mut self, ratio: f32) -> Result<&mut Self, SettingErr> {
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(SettingErr::SellRefundRatio(ratio));
+        }
+        self.sell_refund_ratio = ratio;
+        Ok(self)
+    }
+
+    pub fn road_clearance(&self) -> f32 {
+        self.road_clearance
+    }
+
+    /// Minimal distance between a new tower and the road
+    pub fn set_road_clearance(&mut self, clearance: f32) -> Result<&mut Self, SettingErr> {
+        if !clearance.is_finite() || clearance < 0.0 {
+            return Err(SettingErr::RoadClearance(clearance));
+        }
+        self.road_clearance = clearance;
+        Ok(self)
+    }
+
+    pub fn bounds(&self) -> MapBounds {
+        self.bounds
+    }
+
+    pub fn set_bounds(&mut self, bounds: MapBounds) -> Result<&mut Self, SettingErr> {
+        if !bounds.is_valid() {
+            return Err(SettingErr::Bounds(bounds));
+        }
+        self.bounds = bounds;
+        Ok(self)
+    }
+
+    pub fn interest(&self) -> Option<Interest> {
+        self.interest
+    }
+
+    pub fn set_interest(&mut self, interest: Option<Interest>) -> &mut Self {
+        self.interest = interest;
+        self
     }
 }
 
-impl Error for TooTightTowerPlacementErr {
-    fn cause(&self) -> Option<&dyn Error> {
-        None
+impl<S: Spawner + Clone, T: Trajectory + Clone> ConcreteGameModel<S, T> {
+    pub fn snapshot(&self) -> Result<GameSnapshot<S, T>, SnapshotErr> {
+        let projectiles = self
+            .projectiles
+            .iter()
+            .map(|projectile| projectile.snapshot(self.content))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(GameSnapshot {
+            seed: self.seed,
+            rng: self.rng.clone(),
+            min_tower_gap: self.min_tower_gap,
+            road_clearance: Some(self.road_clearance),
+            bounds: Some(self.bounds),
+            sell_refund_ratio: self.sell_refund_ratio,
+            interest: self.interest,
+            trajectory: self.trajectory.clone(),
+            spawner: self.spawner.clone(),
+            towers: self.towers.iter().map(|tower| tower.snapshot()).collect(),
+            selected_tower: self.selected_tower,
+            enemies: self.enemies.map(|enemy| enemy.snapshot()),
+            projectiles,
+            explosions: self.explosions.clone(),
+            wallet: self.player_wallet,
+            lives: self.player_lives,
+            stats: self.stats.clone(),
+            ledger: self.ledger.clone(),
+            outcome: self.outcome,
+        })
+    }
+
+    pub fn restore(snapshot: GameSnapshot<S, T>, content: Content) -> Result<Self, SnapshotErr> {
+        let enemies = snapshot
+            .enemies
+            .try_map(|id, enemy| Enemy::restore(id, enemy, content))?;
+        let towers = snapshot
+            .towers
+            .into_iter()
+            .map(|tower| Tower::restore(tower, content))
+            .collect::<Result<Vec<_>, _>>()?;
+        let projectiles = snapshot
+            .projectiles
+            .into_iter()
+            .map(|projectile| Projectile::restore(projectile, content))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut spawner = snapshot.spawner;
+        spawner.restore_content(content);
+        let road = Polyline::sample(&snapshot.trajectory, Self::ROAD_LEN);
+
+        Ok(Self {
+            content,
+            seed: snapshot.seed,
+            rng: snapshot.rng,
+            min_tower_gap: snapshot.min_tower_gap,
+            road_clearance: snapshot
+                .road_clearance
+                .unwrap_or(Self::DEFAULT_ROAD_CLEARANCE),
+            bounds: snapshot.bounds.unwrap_or(Self::DEFAULT_BOUNDS),
+            sell_refund_ratio: snapshot.sell_refund_ratio,
+            interest: snapshot.interest,
+            trajectory: snapshot.trajectory,
+            road,
+            spawner,
+            selected_tower: snapshot.selected_tower.filter(|idx| *idx < towers.len()),
+            towers,
+            enemies,
+            projectiles,
+            explosions: snapshot.explosions,
+            player_wallet: snapshot.wallet,
+            player_lives: snapshot.lives,
+            stats: snapshot.stats,
+            ledger: snapshot.ledger,
+            events: Vec::new(),
+            outcome: snapshot.outcome,
+        })
     }
 }
 
 impl<S: Spawner, T: Trajectory> GameModel for ConcreteGameModel<S, T> {
     fn update(&mut self, delta_time: Duration) {
-        let enemies = std::mem::take(&mut self.enemies);
-        for enemy in enemies.iter() {
-            enemy.borrow_mut().on_update(self, delta_time);
+        if self.is_over() {
+            return;
+        }
+        self.stats.record_time(delta_time);
+
+        let mut enemies = std::mem::take(&mut self.enemies);
+        for (_, enemy) in enemies.iter_mut() {
+            enemy.on_update(self, delta_time);
         }
         self.enemies = enemies;
+        self.leak_enemies();
 
         let mut towers = std::mem::take(&mut self.towers);
         for tower in towers.iter_mut() {
@@ -94,6 +351,12 @@ impl<S: Spawner, T: Trajectory> GameModel for ConcreteGameModel<S, T> {
         }
         self.towers = towers;
 
+        let mut explosions = std::mem::take(&mut self.explosions);
+        for explosion in explosions.iter_mut() {
+            explosion.on_update(self, delta_time);
+        }
+        self.explosions = explosions;
+
         let mut projectiles = std::mem::take(&mut self.projectiles);
         for projectile in projectiles.iter_mut() {
             projectile.on_update(self, delta_time);
@@ -101,19 +364,42 @@ impl<S: Spawner, T: Trajectory> GameModel for ConcreteGameModel<S, T> {
         self.projectiles = projectiles;
 
         self.projectiles.retain(|projectile| projectile.is_active());
-        self.enemies.retain(|enemy| !enemy.borrow().is_dead());
+        self.explosions.retain(|explosion| explosion.is_active());
+        self.enemies.retain(|_, enemy| !enemy.is_dead());
 
         let mut spawner = std::mem::take(&mut self.spawner);
         spawner.on_update(self, delta_time);
         self.spawner = spawner;
+
+        self.update_outcome();
     }
 
     fn wallet(&self) -> Wallet {
         self.player_wallet
     }
 
-    fn is_over(&self) -> bool {
-        self.is_overrun()
+    fn content(&self) -> Content {
+        self.content
+    }
+
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn rng(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.outcome
+    }
+
+    fn quit(&mut self) {
+        self.outcome.get_or_insert(GameOutcome::Quit);
+    }
+
+    fn wave_status(&self) -> Option<WaveStatus> {
+        self.spawner.wave_status()
     }
 
     fn towers(&self) -> &Vec<Tower> {
@@ -124,38 +410,172 @@ impl<S: Spawner, T: Trajectory> GameModel for ConcreteGameModel<S, T> {
         &self.trajectory
     }
 
-    fn enemies(&self) -> &Vec<EnemyShared> {
-        &self.enemies
+    fn enemies(&self) -> ArenaIter<'_, Enemy> {
+        self.enemies.iter()
+    }
+
+    fn enemy(&self, id: EnemyId) -> Option<&Enemy> {
+        self.enemies.get(id)
+    }
+
+    fn enemy_mut(&mut self, id: EnemyId) -> Option<&mut Enemy> {
+        self.enemies.get_mut(id)
     }
 
     fn projectiles(&self) -> &Vec<Projectile> {
         &self.projectiles
     }
 
+    fn explosions(&self) -> &Vec<Explosion> {
+        &self.explosions
+    }
+
     fn wallet_mut(&mut self) -> &mut Wallet {
         &mut self.player_wallet
     }
 
+    fn lives(&self) -> Lives {
+        self.player_lives
+    }
+
+    fn stats(&self) -> &GameStats {
+        &self.stats
+    }
+
+    fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    fn reward_wave(&mut self, wave: usize, bonus: u64) {
+        let interest = self
+            .interest
+            .map_or(0, |interest| interest.on(self.player_wallet.balance()));
+        self.player_wallet.add_money(bonus.saturating_add(interest));
+        self.emit(GameEvent::WaveEnded {
+            wave,
+            bonus,
+            interest,
+        });
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        self.stats.observe(&event);
+        self.ledger.observe(&event, self.stats.time_survived());
+        self.events.push(event);
+    }
+
+    fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     fn spawn_projectile(&mut self, projectile: Projectile) {
         self.projectiles.push(projectile)
     }
 
+    fn spawn_explosion(&mut self, explosion: Explosion) {
+        self.explosions.push(explosion)
+    }
+
     fn spawn_tower(&mut self, new_tower: Tower) -> Result<(), Box<dyn Error>> {
+        self.check_placement(new_tower.position())?;
+
+        let cost = new_tower.cost();
+        let event = GameEvent::TowerBuilt {
+            tower: new_tower.type_info(),
+            position: new_tower.position(),
+            cost,
+        };
+        self.player_wallet
+            .pay_to_do(cost, || self.towers.push(new_tower))?;
+        self.emit(event);
+        Ok(())
+    }
+
+    fn check_placement(&self, position: Point) -> Result<(), PlacementErr> {
+        if !self.bounds.contains(position) {
+            return Err(PlacementErr::OutOfBounds);
+        }
+        if self.road.distance_to(position) < self.road_clearance {
+            return Err(PlacementErr::TooCloseToRoad);
+        }
         if self
             .towers
             .iter()
-            .any(|tower| tower.position().distance(new_tower.position()) < self.min_tower_gap)
+            .any(|tower| tower.position().distance(position) < self.min_tower_gap)
         {
-            return Err(Box::new(TooTightTowerPlacementErr {}));
+            return Err(PlacementErr::TooCloseToTower);
         }
+        Ok(())
+    }
 
-        self.player_wallet
-            .pay_to_do(new_tower.cost(), || self.towers.push(new_tower))?;
+    fn spawn_enemy(&mut self, type_info: &'static EnemyInfo) -> EnemyId {
+        let id = self.enemies.insert_with(|id| Enemy::new(id, type_info));
+        self.emit(GameEvent::EnemySpawned {
+            id,
+            enemy: type_info,
+        });
+        id
+    }
+
+    fn tower_at(&self, position: Point) -> Option<usize> {
+        self.towers
+            .iter()
+            .enumerate()
+            .map(|(idx, tower)| (idx, tower.position().distance(position)))
+            .filter(|(_, distance)| *distance < self.min_tower_gap)
+            .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+            .map(|(idx, _)| idx)
+    }
+
+    fn selected_tower(&self) -> Option<&Tower> {
+        self.selected_tower.and_then(|idx| self.towers.get(idx))
+    }
+
+    fn selected_tower_mut(&mut self) -> Option<&mut Tower> {
+        self.selected_tower.and_then(|idx| self.towers.get_mut(idx))
+    }
+
+    fn select_tower(&mut self, idx: Option<usize>) {
+        self.selected_tower = idx.filter(|idx| *idx < self.towers.len());
+    }
+
+    fn upgrade_selected_tower(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(tower) = self.selected_tower.and_then(|idx| self.towers.get_mut(idx)) else {
+            return Ok(());
+        };
+        let upgrade = tower.next_upgrade().ok_or(MaxTowerLevelErr {})?;
+
+        self.player_wallet.take_money(upgrade.cost)?;
+        tower.upgrade()?;
+        let event = GameEvent::TowerUpgraded {
+            tower: tower.type_info(),
+            position: tower.position(),
+            level: tower.display_level(),
+            cost: upgrade.cost,
+        };
+        self.emit(event);
         Ok(())
     }
 
-    fn spawn_enemy(&mut self, enemy: Enemy) {
-        self.enemies.push(Rc::new(RefCell::new(enemy)))
+    fn sell_tower(&mut self, idx: usize) -> Option<u64> {
+        if idx >= self.towers.len() {
+            return None;
+        }
+        let tower = self.towers.remove(idx);
+        self.selected_tower = match self.selected_tower {
+            Some(selected) if selected == idx => None,
+            Some(selected) if selected > idx => Some(selected - 1),
+            selected => selected,
+        };
+
+        let refund = (tower.invested() as f32 * self.sell_refund_ratio) as u64;
+        self.player_wallet.add_money(refund);
+        self.emit(GameEvent::TowerSold {
+            tower: tower.type_info(),
+            position: tower.position(),
+            refund,
+        });
+        Some(refund)
     }
 
     fn min_tower_gap(&self) -> f32 {
@@ -164,9 +584,133 @@ impl<S: Spawner, T: Trajectory> GameModel for ConcreteGameModel<S, T> {
 }
 
 impl<S: Spawner, T: Trajectory> ConcreteGameModel<S, T> {
-    fn is_overrun(&self) -> bool {
-        self.enemies
-            .iter()
-            .any(|enemy| enemy.borrow().t_position() > Self::ROAD_LEN)
+    fn update_outcome(&mut self) {
+        if self.player_lives.is_depleted() {
+            self.outcome = Some(GameOutcome::Defeat);
+        } else if self.spawner.is_finished() {
+            self.outcome = Some(GameOutcome::Victory);
+        }
+    }
+
+    fn leak_enemies(&mut self) {
+        let lives = &mut self.player_lives;
+        let mut leaked = Vec::new();
+        self.enemies.retain(|id, enemy| {
+            // Enemies which died on the way out were already rewarded as kills,
+            // they are removed with the rest of the dead
+            if enemy.is_dead() || enemy.t_position() <= Self::ROAD_LEN {
+                return true;
+            }
+            lives.take_damage(enemy.leak_damage());
+            leaked.push(GameEvent::EnemyLeaked {
+                id,
+                enemy: enemy.type_info(),
+                lives_lost: enemy.leak_damage(),
+            });
+            false
+        });
+        for event in leaked {
+            self.emit(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{SineTrajectory, WaveSpawner};
+
+    fn game() -> ConcreteGameModel<WaveSpawner, SineTrajectory> {
+        let content = Content::builtin();
+        ConcreteGameModel::new(
+            content,
+            1,
+            WaveSpawner::new(content.waves),
+            |_| SineTrajectory::new(0.0),
+            1000,
+            20,
+            1.0,
+        )
+    }
+
+    /// Free position straight above the road, at least `gap` away from it
+    fn beside_road(game: &impl GameModel, t: f32, gap: f32) -> Point {
+        let road = game.trajectory().point_from_t(t);
+        (0..100)
+            .map(|step| Point {
+                x: road.x,
+                y: road.y + gap + step as f32 * 0.1,
+            })
+            .find(|&position| game.check_placement(position).is_ok())
+            .expect("no free place next to the road")
+    }
+
+    #[test]
+    fn placement_checks_bounds_road_and_towers() {
+        let mut game = game();
+        let road = game.trajectory().point_from_t(50.0);
+        assert_eq!(
+            game.check_placement(road),
+            Err(PlacementErr::TooCloseToRoad)
+        );
+        assert_eq!(
+            game.check_placement(Point { x: -5.0, y: 0.0 }),
+            Err(PlacementErr::OutOfBounds)
+        );
+
+        let position = beside_road(&game, 50.0, 1.0);
+        let tower = Tower::new(position, &game.content().towers[0]);
+        game.spawn_tower(tower).unwrap();
+        let next_to_tower = Point {
+            y: position.y + 0.5,
+            ..position
+        };
+        assert_eq!(
+            game.check_placement(next_to_tower),
+            Err(PlacementErr::TooCloseToTower)
+        );
+    }
+
+    #[test]
+    fn placement_follows_configured_clearance_and_bounds() {
+        let mut game = game();
+        let position = beside_road(&game, 50.0, 1.0);
+        let clearance = game.road.distance_to(position);
+
+        game.set_road_clearance(clearance + 1.0).unwrap();
+        assert_eq!(
+            game.check_placement(position),
+            Err(PlacementErr::TooCloseToRoad)
+        );
+        game.set_road_clearance(clearance).unwrap();
+        assert_eq!(game.check_placement(position), Ok(()));
+
+        let bounds = MapBounds {
+            min: Point { x: 0.0, y: 0.0 },
+            max: Point { x: 10.0, y: 10.0 },
+        };
+        game.set_bounds(bounds).unwrap();
+        assert_eq!(
+            game.check_placement(position),
+            Err(PlacementErr::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn setters_reject_invalid_values() {
+        let mut game = game();
+        assert_eq!(
+            game.set_road_clearance(-0.5).err(),
+            Some(SettingErr::RoadClearance(-0.5))
+        );
+        let bounds = MapBounds {
+            min: Point { x: 1.0, y: 0.0 },
+            max: Point { x: 0.0, y: 1.0 },
+        };
+        assert_eq!(
+            game.set_bounds(bounds).err(),
+            Some(SettingErr::Bounds(bounds))
+        );
+        assert_eq!(game.road_clearance(), 1.0);
     }
 }
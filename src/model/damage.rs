@@ -1,26 +1,93 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DamageType {
     Kinnetic,
     Magic,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Damage {
     pub value: f32,
     pub kind: DamageType,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Resistances {
+    pub kinnetic: f32,
+    pub magic: f32,
+}
+
+impl DamageType {
+    pub const ALL: [DamageType; 2] = [DamageType::Kinnetic, DamageType::Magic];
+
+    /// Short form used after damage values
+    pub fn symbol(&self) -> char {
+        match self {
+            DamageType::Kinnetic => 'K',
+            DamageType::Magic => 'M',
+        }
+    }
+}
+
+impl Resistances {
+    pub const NONE: Resistances = Resistances {
+        kinnetic: 1.0,
+        magic: 1.0,
+    };
+
+    pub fn multiplier(&self, kind: DamageType) -> f32 {
+        match kind {
+            DamageType::Kinnetic => self.kinnetic,
+            DamageType::Magic => self.magic,
+        }
+    }
+
+    pub fn eval(&self, damage: Damage) -> f32 {
+        damage.value * self.multiplier(damage.kind)
+    }
+}
+
+impl Default for Resistances {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// Lists damage reduction per damage type, weaknesses are negative
+impl Display for Resistances {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let resistances = DamageType::ALL
+            .into_iter()
+            .filter(|&kind| self.multiplier(kind) != 1.0)
+            .map(|kind| {
+                let percent = ((1.0 - self.multiplier(kind)) * 100.0).round();
+                format!("{} {}%", kind, percent)
+            })
+            .collect::<Vec<_>>();
+        if resistances.is_empty() {
+            return write!(f, "none");
+        }
+        write!(f, "{}", resistances.join(", "))
+    }
+}
+
 impl Display for DamageType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let text = format!("{:?}", self);
-        write!(f, "{}", text.chars().next().unwrap())
+        let text = match self {
+            DamageType::Kinnetic => "kinetic",
+            DamageType::Magic => "magic",
+        };
+        write!(f, "{}", text)
     }
 }
 
 impl Display for Damage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.value, self.kind)
+        write!(f, "{}{}", self.value, self.kind.symbol())
     }
 }
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::event::GameEvent;
+
+/// Share of the banked gold paid out at the end of every wave
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Interest {
+    percent: u64,
+}
+
+impl Interest {
+    pub fn new(percent: u64) -> Self {
+        Self { percent }
+    }
+
+    pub fn percent(&self) -> u64 {
+        self.percent
+    }
+
+    pub fn on(&self, balance: u64) -> u64 {
+        balance.saturating_mul(self.percent) / 100
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TransactionKind {
+    KillBounty,
+    WaveBonus,
+    Interest,
+    TowerBuilt,
+    TowerUpgraded,
+    TowerSold,
+}
+
+impl TransactionKind {
+    /// Refunds only give back spent money, so they are not counted as income
+    pub fn is_income(&self) -> bool {
+        matches!(
+            self,
+            TransactionKind::KillBounty | TransactionKind::WaveBonus | TransactionKind::Interest
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Transaction {
+    pub kind: TransactionKind,
+    pub amount: u64,
+    /// Game time at which the money changed hands
+    pub time: Duration,
+}
+
+/// Every change of the player's balance, collected from game events
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    transactions: Vec<Transaction>,
+}
+
+impl Ledger {
+    const INCOME_WINDOW: Duration = Duration::from_secs(60);
+
+    pub fn transactions(&self) -> &[Transaction] {
+        &self.transactions
+    }
+
+    pub fn observe(&mut self, event: &GameEvent, time: Duration) {
+        let mut record = |kind, amount| {
+            if amount > 0 {
+                self.transactions.push(Transaction { kind, amount, time });
+            }
+        };
+        match *event {
+            GameEvent::EnemyKilled { reward, .. } => record(TransactionKind::KillBounty, reward),
+            GameEvent::WaveEnded {
+                bonus, interest, ..
+            } => {
+                record(TransactionKind::WaveBonus, bonus);
+                record(TransactionKind::Interest, interest);
+            }
+            GameEvent::TowerBuilt { cost, .. } => record(TransactionKind::TowerBuilt, cost),
+            GameEvent::TowerUpgraded { cost, .. } => record(TransactionKind::TowerUpgraded, cost),
+            GameEvent::TowerSold { refund, .. } => record(TransactionKind::TowerSold, refund),
+            _ => {}
+        }
+    }
+
+    pub fn total(&self, kind: TransactionKind) -> u64 {
+        self.transactions
+            .iter()
+            .filter(|transaction| transaction.kind == kind)
+            .map(|transaction| transaction.amount)
+            .sum()
+    }
+
+    /// Income earned during the last minute of game time
+    pub fn income_per_minute(&self, now: Duration) -> u64 {
+        let since = now.saturating_sub(Self::INCOME_WINDOW);
+        self.transactions
+            .iter()
+            .rev()
+            .take_while(|transaction| transaction.time > since)
+            .filter(|transaction| transaction.kind.is_income())
+            .map(|transaction| transaction.amount)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wave_ended(bonus: u64, interest: u64) -> GameEvent {
+        GameEvent::WaveEnded {
+            wave: 1,
+            bonus,
+            interest,
+        }
+    }
+
+    #[test]
+    fn income_older_than_a_minute_drops_out() {
+        let mut ledger = Ledger::default();
+        ledger.observe(&wave_ended(10, 0), Duration::from_secs(5));
+        ledger.observe(&wave_ended(20, 5), Duration::from_secs(40));
+
+        assert_eq!(ledger.income_per_minute(Duration::from_secs(50)), 35);
+        // Income exactly a minute old is outside the window
+        assert_eq!(ledger.income_per_minute(Duration::from_secs(65)), 25);
+        assert_eq!(ledger.income_per_minute(Duration::from_secs(101)), 0);
+        assert_eq!(ledger.total(TransactionKind::WaveBonus), 30);
+    }
+}
@@ -1,42 +1,59 @@
 use std::time::Duration;
 
-use crate::ui::PointDrawInfo;
+use crate::{content::Content, ui::PointDrawInfo};
 
 use super::{
-    core::{GameModel, UpdatableObject},
-    damage::Damage,
+    core::{EnemyId, GameModel, UpdatableObject},
+    damage::{Damage, Resistances},
+    event::GameEvent,
+    snapshot::{EnemySnapshot, SnapshotErr},
+    status::{StatusEffect, StatusEffects},
     trajectory::Trajectory,
 };
 
 #[derive(Debug, Clone)]
 pub struct EnemyInfo {
+    pub name: &'static str,
     pub max_health: f32,
     pub speed: f32,
     pub reward: u64,
-    pub damage_eval: fn(Damage) -> f32,
+    pub leak_damage: u64,
+    pub resistances: Resistances,
     pub draw_info: PointDrawInfo,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Enemy {
+    id: EnemyId,
     health: f32,
     t_position: f32,
+    status_effects: StatusEffects,
     type_info: &'static EnemyInfo,
 }
 
 impl Enemy {
-    pub fn new(type_info: &'static EnemyInfo) -> Self {
+    pub fn new(id: EnemyId, type_info: &'static EnemyInfo) -> Self {
         Self {
+            id,
             health: type_info.max_health,
             t_position: 0.0,
-            type_info: type_info,
+            status_effects: StatusEffects::default(),
+            type_info,
         }
     }
 
+    pub fn id(&self) -> EnemyId {
+        self.id
+    }
+
     pub fn t_position(&self) -> f32 {
         self.t_position
     }
 
+    pub fn health(&self) -> f32 {
+        self.health
+    }
+
     pub fn is_dead(&self) -> bool {
         self.health <= 0.0
     }
@@ -45,18 +62,65 @@ impl Enemy {
         self.type_info.reward
     }
 
-    pub fn take_damage(&mut self, damage: Damage) {
-        self.health -= (self.type_info.damage_eval)(damage);
+    pub fn leak_damage(&self) -> u64 {
+        self.type_info.leak_damage
+    }
+
+    pub fn evaluate_damage(&self, damage: Damage) -> f32 {
+        self.type_info.resistances.eval(damage)
+    }
+
+    /// Returns how much health the enemy actually lost
+    pub fn take_damage(&mut self, damage: Damage) -> f32 {
+        let damage = self.evaluate_damage(damage);
+        let dealt = damage.min(self.health.max(0.0));
+        self.health -= damage;
+        dealt
+    }
+
+    pub fn status_effects(&self) -> &StatusEffects {
+        &self.status_effects
+    }
+
+    pub fn apply_effect(&mut self, effect: StatusEffect) {
+        self.status_effects.apply(effect);
     }
 
     pub fn type_info(&self) -> &'static EnemyInfo {
-        &self.type_info
+        self.type_info
+    }
+
+    pub fn snapshot(&self) -> EnemySnapshot {
+        EnemySnapshot {
+            type_name: self.type_info.name.to_owned(),
+            health: self.health,
+            t_position: self.t_position,
+            status_effects: self.status_effects.clone(),
+        }
+    }
+
+    pub fn restore(
+        id: EnemyId,
+        snapshot: EnemySnapshot,
+        content: Content,
+    ) -> Result<Self, SnapshotErr> {
+        let type_info = content
+            .enemy(&snapshot.type_name)
+            .ok_or(SnapshotErr::UnknownEnemy(snapshot.type_name))?;
+        Ok(Self {
+            id,
+            health: snapshot.health,
+            t_position: snapshot.t_position,
+            status_effects: snapshot.status_effects,
+            type_info,
+        })
     }
 }
 
 impl UpdatableObject for Enemy {
     fn on_update(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
         self.move_forward(delta_time, game_model.trajectory());
+        self.tick_effects(game_model, delta_time);
     }
 }
 
@@ -66,7 +130,8 @@ impl Enemy {
         const EPSILON_MULTIPLYER: f32 = 1e2;
         const EPSILON: f32 = f32::EPSILON * EPSILON_MULTIPLYER;
 
-        let mut move_points = self.type_info.speed * delta_time.as_secs_f32();
+        let speed = self.type_info.speed * self.status_effects.speed_multiplier();
+        let mut move_points = speed * delta_time.as_secs_f32();
         let mut step = INITIAL_STEP;
         while move_points > EPSILON {
             let t_to_move_to = self.t_position + step;
@@ -84,3 +149,22 @@ impl Enemy {
         }
     }
 }
+
+impl Enemy {
+    fn tick_effects(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
+        for (damage, source) in self.status_effects.tick(delta_time) {
+            if self.is_dead() {
+                return;
+            }
+            let amount = self.take_damage(damage);
+            game_model.emit(GameEvent::EnemyDamaged {
+                id: self.id,
+                amount,
+                source,
+            });
+            if self.is_dead() {
+                game_model.reward_kill(self.id, self.type_info);
+            }
+        }
+    }
+}
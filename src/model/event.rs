@@ -0,0 +1,57 @@
+use super::{core::EnemyId, enemy::EnemyInfo, point::Point, tower::TowerInfo};
+
+/// Something that happened in the game, emitted by the model so that
+/// statistics, UI and other subsystems don't need hooks of their own
+#[derive(Debug, Clone, Copy)]
+pub enum GameEvent {
+    EnemySpawned {
+        id: EnemyId,
+        enemy: &'static EnemyInfo,
+    },
+    /// `amount` is the health actually lost, `source` names the tower type
+    EnemyDamaged {
+        id: EnemyId,
+        amount: f32,
+        source: Option<&'static str>,
+    },
+    EnemyKilled {
+        id: EnemyId,
+        enemy: &'static EnemyInfo,
+        reward: u64,
+    },
+    EnemyLeaked {
+        id: EnemyId,
+        enemy: &'static EnemyInfo,
+        lives_lost: u64,
+    },
+    TowerBuilt {
+        tower: &'static TowerInfo,
+        position: Point,
+        cost: u64,
+    },
+    /// `level` is the level reached, counted from 1
+    TowerUpgraded {
+        tower: &'static TowerInfo,
+        position: Point,
+        level: usize,
+        cost: u64,
+    },
+    TowerSold {
+        tower: &'static TowerInfo,
+        position: Point,
+        refund: u64,
+    },
+    ProjectileFired {
+        source: &'static str,
+        position: Point,
+        target: EnemyId,
+    },
+    /// Waves are counted from 1
+    WaveStarted { wave: usize },
+    /// `bonus` and `interest` are paid to the player when the wave ends
+    WaveEnded {
+        wave: usize,
+        bonus: u64,
+        interest: u64,
+    },
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    core::{GameModel, UpdatableObject},
+    point::{Point, Positioned},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Explosion {
+    position: Point,
+    radius: f32,
+    remaining: Duration,
+}
+
+impl Explosion {
+    const LIFETIME: Duration = Duration::from_millis(200);
+
+    pub fn new(position: Point, radius: f32) -> Self {
+        Self {
+            position,
+            radius,
+            remaining: Self::LIFETIME,
+        }
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.remaining.is_zero()
+    }
+}
+
+impl Positioned for Explosion {
+    fn position(&self) -> Point {
+        self.position
+    }
+}
+
+impl UpdatableObject for Explosion {
+    fn on_update(&mut self, _: &mut impl GameModel, delta_time: Duration) {
+        self.remaining = self.remaining.saturating_sub(delta_time);
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Lives {
+    remaining: u64,
+}
+
+impl Lives {
+    pub fn new(remaining: u64) -> Self {
+        Self { remaining }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    pub fn take_damage(&mut self, damage: u64) -> &Self {
+        self.remaining = self.remaining.saturating_sub(damage);
+        self
+    }
+
+    pub fn is_depleted(&self) -> bool {
+        self.remaining == 0
+    }
+}
@@ -1,3 +1,6 @@
+mod arena;
+pub use arena::*;
+
 mod clock;
 pub use clock::*;
 
@@ -7,15 +10,42 @@ pub use core::*;
 mod damage;
 pub use damage::*;
 
+mod economy;
+pub use economy::*;
+
 mod enemy;
 pub use enemy::*;
 
+mod event;
+pub use event::*;
+
+mod explosion;
+pub use explosion::*;
+
+mod lives;
+pub use lives::*;
+
+mod placement;
+pub use placement::*;
+
 mod point;
 pub use point::*;
 
+mod snapshot;
+pub use snapshot::*;
+
 mod spawner;
 pub use spawner::*;
 
+mod stats;
+pub use stats::*;
+
+mod status;
+pub use status::*;
+
+mod targeting;
+pub use targeting::*;
+
 mod tower;
 pub use tower::*;
 
@@ -0,0 +1,79 @@
+use std::{error::Error, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use super::point::Point;
+
+/// Rectangle in world coordinates inside which towers can be built
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct MapBounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl MapBounds {
+    /// Finite and not empty
+    pub fn is_valid(&self) -> bool {
+        [self.min.x, self.min.y, self.max.x, self.max.y]
+            .iter()
+            .all(|coord| coord.is_finite())
+            && self.min.x <= self.max.x
+            && self.min.y <= self.max.y
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlacementErr {
+    OutOfBounds,
+    TooCloseToRoad,
+    TooCloseToTower,
+}
+
+impl Display for PlacementErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementErr::OutOfBounds => write!(f, "Tried to build tower outside of the map"),
+            PlacementErr::TooCloseToRoad => write!(f, "Tried to build tower too close to road"),
+            PlacementErr::TooCloseToTower => {
+                write!(f, "Tried to build tower too close to other tower")
+            }
+        }
+    }
+}
+
+impl Error for PlacementErr {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> MapBounds {
+        MapBounds {
+            min: Point { x: min_x, y: min_y },
+            max: Point { x: max_x, y: max_y },
+        }
+    }
+
+    #[test]
+    fn valid_bounds_are_finite_and_ordered() {
+        assert!(bounds(0.0, -1.0, 10.0, 1.0).is_valid());
+        assert!(bounds(0.0, 0.0, 0.0, 0.0).is_valid());
+        assert!(!bounds(10.0, -1.0, 0.0, 1.0).is_valid());
+        assert!(!bounds(0.0, 1.0, 10.0, -1.0).is_valid());
+        assert!(!bounds(0.0, -1.0, f32::INFINITY, 1.0).is_valid());
+        assert!(!bounds(f32::NAN, -1.0, 10.0, 1.0).is_valid());
+    }
+
+    #[test]
+    fn contains_edges() {
+        let bounds = bounds(0.0, -1.0, 10.0, 1.0);
+        assert!(bounds.contains(Point { x: 0.0, y: 1.0 }));
+        assert!(bounds.contains(Point { x: 10.0, y: -1.0 }));
+        assert!(!bounds.contains(Point { x: 10.1, y: 0.0 }));
+        assert!(!bounds.contains(Point { x: 5.0, y: -1.1 }));
+    }
+}
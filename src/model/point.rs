@@ -1,6 +1,8 @@
 use std::ops::{Add, Mul, Sub};
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
@@ -15,6 +17,10 @@ impl Point {
         ((self.x - rhs.x).powi(2) + (self.y - rhs.y).powi(2)).sqrt()
     }
 
+    pub fn dot(&self, rhs: Point) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
     pub fn normalize(&self) -> Point {
         let len = self.distance(Point::default());
         Point {
@@ -0,0 +1,96 @@
+use std::{error::Error, fmt::Display, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    arena::Arena,
+    core::{EnemyId, GameRng},
+    economy::{Interest, Ledger},
+    explosion::Explosion,
+    lives::Lives,
+    placement::MapBounds,
+    point::Point,
+    stats::{GameOutcome, GameStats},
+    status::StatusEffects,
+    targeting::Targeting,
+    wallet::Wallet,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SnapshotErr {
+    UnknownEnemy(String),
+    UnknownTower(String),
+    UnknownTowerLevel(String, usize),
+}
+
+impl Display for SnapshotErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotErr::UnknownEnemy(name) => {
+                write!(f, "Saved game references unknown enemy type '{}'", name)
+            }
+            SnapshotErr::UnknownTower(name) => {
+                write!(f, "Saved game references unknown tower type '{}'", name)
+            }
+            SnapshotErr::UnknownTowerLevel(name, level) => {
+                write!(f, "Tower type '{}' has no level {}", name, level + 1)
+            }
+        }
+    }
+}
+
+impl Error for SnapshotErr {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnemySnapshot {
+    pub(super) type_name: String,
+    pub(super) health: f32,
+    pub(super) t_position: f32,
+    pub(super) status_effects: StatusEffects,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TowerSnapshot {
+    pub(super) type_name: String,
+    pub(super) position: Point,
+    pub(super) cooldown_elapsed: Duration,
+    pub(super) level: usize,
+    pub(super) targeting: Targeting,
+    pub(super) aim: Option<EnemyId>,
+}
+
+/// Projectile stats are looked up by the tower type and level which fired it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectileSnapshot {
+    pub(super) tower_name: String,
+    pub(super) level: usize,
+    pub(super) position: Point,
+    pub(super) aim: Option<EnemyId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshot<S, T> {
+    pub(super) seed: u64,
+    pub(super) rng: GameRng,
+    pub(super) min_tower_gap: f32,
+    #[serde(default)]
+    pub(super) road_clearance: Option<f32>,
+    #[serde(default)]
+    pub(super) bounds: Option<MapBounds>,
+    pub(super) sell_refund_ratio: f32,
+    #[serde(default)]
+    pub(super) interest: Option<Interest>,
+    pub(super) trajectory: T,
+    pub(super) spawner: S,
+    pub(super) towers: Vec<TowerSnapshot>,
+    pub(super) selected_tower: Option<usize>,
+    pub(super) enemies: Arena<EnemySnapshot>,
+    pub(super) projectiles: Vec<ProjectileSnapshot>,
+    pub(super) explosions: Vec<Explosion>,
+    pub(super) wallet: Wallet,
+    pub(super) lives: Lives,
+    pub(super) stats: GameStats,
+    #[serde(default)]
+    pub(super) ledger: Ledger,
+    pub(super) outcome: Option<GameOutcome>,
+}
@@ -1,27 +1,50 @@
 use std::time::Duration;
 
-use rand::{seq::SliceRandom, thread_rng};
-
-use crate::assets::enemy::*;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 use super::{
     core::{GameModel, UpdatableObject},
-    enemy::Enemy,
+    enemy::EnemyInfo,
+    event::GameEvent,
 };
+use crate::content::Content;
+
+pub trait Spawner: UpdatableObject + Default {
+    fn wave_status(&self) -> Option<WaveStatus> {
+        None
+    }
+
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    /// Reattaches content references which are not stored in save files
+    fn restore_content(&mut self, _content: Content) {}
+}
 
-pub trait Spawner: UpdatableObject + Default {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WaveStatus {
+    pub wave: usize,
+    pub total_waves: usize,
+    pub waves_cleared: usize,
+    pub until_next_wave: Option<Duration>,
+}
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RandomizedSpawnerWithCooldown {
     cooldown_elapsed: Duration,
     cooldown: Duration,
+    #[serde(skip)]
+    enemies: &'static [EnemyInfo],
 }
 
 impl RandomizedSpawnerWithCooldown {
-    pub fn new(cooldown: Duration) -> Self {
+    pub fn new(cooldown: Duration, enemies: &'static [EnemyInfo]) -> Self {
         Self {
             cooldown_elapsed: Duration::from_millis(0),
-            cooldown: cooldown,
+            cooldown,
+            enemies,
         }
     }
 }
@@ -39,17 +62,350 @@ impl UpdatableObject for RandomizedSpawnerWithCooldown {
 
 impl RandomizedSpawnerWithCooldown {
     fn spawn(&self, game_model: &mut impl GameModel) {
-        game_model.spawn_enemy(Self::produce_enemy())
+        if let Some(enemy) = self.enemies.choose(game_model.rng()) {
+            game_model.spawn_enemy(enemy);
+        }
+    }
+}
+
+impl Spawner for RandomizedSpawnerWithCooldown {
+    fn restore_content(&mut self, content: Content) {
+        self.enemies = content.enemies;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyGroup {
+    pub enemy_info: &'static EnemyInfo,
+    pub count: usize,
+    pub interval: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Wave {
+    pub delay: Duration,
+    /// Paid to the player once the wave is cleared
+    pub bonus: u64,
+    pub groups: &'static [EnemyGroup],
+}
+
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+enum WavePhase {
+    #[default]
+    Delay,
+    Spawning,
+    Clearing,
+    Finished,
+}
+
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WaveSpawner {
+    #[serde(skip)]
+    schedule: &'static [Wave],
+    wave_idx: usize,
+    group_idx: usize,
+    spawned_in_group: usize,
+    elapsed: Duration,
+    phase: WavePhase,
+}
+
+impl WaveSpawner {
+    pub fn new(schedule: &'static [Wave]) -> Self {
+        Self {
+            schedule,
+            wave_idx: 0,
+            group_idx: 0,
+            spawned_in_group: 0,
+            elapsed: Duration::from_millis(0),
+            phase: WavePhase::Delay,
+        }
+    }
+
+    pub fn current_wave(&self) -> usize {
+        (self.wave_idx + 1).min(self.schedule.len())
+    }
+
+    pub fn time_until_next_wave(&self) -> Option<Duration> {
+        match self.phase {
+            WavePhase::Delay => self
+                .schedule
+                .get(self.wave_idx)
+                .map(|wave| wave.delay.saturating_sub(self.elapsed)),
+            _ => None,
+        }
+    }
+}
+
+impl UpdatableObject for WaveSpawner {
+    fn on_update(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
+        if self.wave_idx >= self.schedule.len() {
+            self.phase = WavePhase::Finished;
+        }
+        self.elapsed += delta_time;
+
+        match self.phase {
+            WavePhase::Delay => {
+                if self.elapsed >= self.schedule[self.wave_idx].delay {
+                    self.start_wave(game_model);
+                }
+            }
+            WavePhase::Spawning => self.spawn_group(game_model),
+            WavePhase::Clearing => {
+                if game_model.enemies().len() == 0 {
+                    self.end_wave(game_model);
+                }
+            }
+            WavePhase::Finished => {}
+        }
+    }
+}
+
+impl WaveSpawner {
+    fn start_wave(&mut self, game_model: &mut impl GameModel) {
+        self.phase = WavePhase::Spawning;
+        self.group_idx = 0;
+        self.spawned_in_group = 0;
+        self.elapsed = Duration::from_millis(0);
+        game_model.emit(GameEvent::WaveStarted {
+            wave: self.wave_idx + 1,
+        });
+    }
+
+    fn end_wave(&mut self, game_model: &mut impl GameModel) {
+        game_model.reward_wave(self.wave_idx + 1, self.schedule[self.wave_idx].bonus);
+        self.wave_idx += 1;
+        self.elapsed = Duration::from_millis(0);
+        self.phase = if self.wave_idx < self.schedule.len() {
+            WavePhase::Delay
+        } else {
+            WavePhase::Finished
+        };
+    }
+
+    fn spawn_group(&mut self, game_model: &mut impl GameModel) {
+        let groups = self.schedule[self.wave_idx].groups;
+        let Some(group) = groups.get(self.group_idx) else {
+            self.phase = WavePhase::Clearing;
+            return;
+        };
+
+        if self.spawned_in_group > 0 && self.elapsed < group.interval {
+            return;
+        }
+
+        if self.spawned_in_group < group.count {
+            game_model.spawn_enemy(group.enemy_info);
+            self.spawned_in_group += 1;
+            self.elapsed = Duration::from_millis(0);
+        }
+
+        if self.spawned_in_group >= group.count {
+            self.group_idx += 1;
+            self.spawned_in_group = 0;
+        }
+    }
+}
+
+impl Spawner for WaveSpawner {
+    fn wave_status(&self) -> Option<WaveStatus> {
+        Some(WaveStatus {
+            wave: self.current_wave(),
+            total_waves: self.schedule.len(),
+            waves_cleared: self.wave_idx,
+            until_next_wave: self.time_until_next_wave(),
+        })
+    }
+
+    fn is_finished(&self) -> bool {
+        self.phase == WavePhase::Finished
+    }
+
+    fn restore_content(&mut self, content: Content) {
+        self.schedule = content.waves;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum AnySpawner {
+    Waves(WaveSpawner),
+    Randomized(RandomizedSpawnerWithCooldown),
+}
+
+impl Default for AnySpawner {
+    fn default() -> Self {
+        AnySpawner::Waves(WaveSpawner::default())
+    }
+}
+
+impl UpdatableObject for AnySpawner {
+    fn on_update(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
+        match self {
+            AnySpawner::Waves(spawner) => spawner.on_update(game_model, delta_time),
+            AnySpawner::Randomized(spawner) => spawner.on_update(game_model, delta_time),
+        }
+    }
+}
+
+impl Spawner for AnySpawner {
+    fn wave_status(&self) -> Option<WaveStatus> {
+        match self {
+            AnySpawner::Waves(spawner) => spawner.wave_status(),
+            AnySpawner::Randomized(spawner) => spawner.wave_status(),
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        match self {
+            AnySpawner::Waves(spawner) => spawner.is_finished(),
+            AnySpawner::Randomized(spawner) => spawner.is_finished(),
+        }
     }
 
-    fn produce_enemy() -> Enemy {
-        const FACTORIES: &[fn() -> Enemy] = &[
-            || Enemy::new(&BASIC_ENEMY_INFO),
-            || Enemy::new(&KINNETIC_RESIST_ENEMY_INFO),
-            || Enemy::new(&MAGIC_RESIST_ENEMY_INFO),
-        ];
-        (FACTORIES.choose(&mut thread_rng()).unwrap())()
+    fn restore_content(&mut self, content: Content) {
+        match self {
+            AnySpawner::Waves(spawner) => spawner.restore_content(content),
+            AnySpawner::Randomized(spawner) => spawner.restore_content(content),
+        }
     }
 }
 
-impl Spawner for RandomizedSpawnerWithCooldown {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        ConcreteGameModel, Damage, DamageType, GameOutcome, Interest, SineTrajectory,
+        SIMULATION_STEP,
+    };
+
+    fn schedule(enemy: &'static EnemyInfo) -> &'static [Wave] {
+        let group = |count| -> &'static [EnemyGroup] {
+            vec![EnemyGroup {
+                enemy_info: enemy,
+                count,
+                interval: Duration::from_millis(100),
+            }]
+            .leak()
+        };
+        vec![
+            Wave {
+                delay: Duration::from_secs(1),
+                bonus: 10,
+                groups: group(2),
+            },
+            Wave {
+                delay: Duration::from_secs(1),
+                bonus: 20,
+                groups: group(1),
+            },
+        ]
+        .leak()
+    }
+
+    fn kill_all(game: &mut impl GameModel) {
+        let ids = game.enemies().map(|(id, _)| id).collect::<Vec<_>>();
+        for id in ids {
+            let damage = Damage {
+                value: f32::MAX,
+                kind: DamageType::Magic,
+            };
+            game.enemy_mut(id).unwrap().take_damage(damage);
+        }
+    }
+
+    fn step_until(spawner: &mut WaveSpawner, game: &mut impl GameModel, phase: WavePhase) {
+        for _ in 0..60 * 60 {
+            if spawner.phase == phase {
+                return;
+            }
+            spawner.on_update(game, SIMULATION_STEP);
+        }
+        panic!("spawner never reached {:?}", phase);
+    }
+
+    #[test]
+    fn goes_through_delay_spawning_and_clearing_until_finished() {
+        let content = Content::builtin();
+        // The model's own spawner never spawns, the tested one is driven by hand
+        let idle = RandomizedSpawnerWithCooldown::new(Duration::MAX, &[]);
+        let mut game =
+            ConcreteGameModel::new(content, 1, idle, |_| SineTrajectory::new(0.0), 0, 20, 1.0);
+        let mut spawner = WaveSpawner::new(schedule(&content.enemies[0]));
+
+        for (wave, enemies) in [(0, 2), (1, 1)] {
+            assert_eq!(spawner.phase, WavePhase::Delay);
+            assert_eq!(spawner.wave_status().unwrap().waves_cleared, wave);
+            step_until(&mut spawner, &mut game, WavePhase::Spawning);
+            step_until(&mut spawner, &mut game, WavePhase::Clearing);
+            assert_eq!(game.enemies().len(), enemies);
+
+            // The wave only ends once its enemies are gone
+            spawner.on_update(&mut game, SIMULATION_STEP);
+            assert_eq!(spawner.phase, WavePhase::Clearing);
+            kill_all(&mut game);
+            game.update(SIMULATION_STEP);
+            spawner.on_update(&mut game, SIMULATION_STEP);
+        }
+        assert_eq!(spawner.phase, WavePhase::Finished);
+        assert!(spawner.is_finished());
+        assert_eq!(spawner.wave_status().unwrap().waves_cleared, 2);
+    }
+
+    #[test]
+    fn pays_bonus_and_interest_after_every_wave_until_finished() {
+        let content = Content::builtin();
+        let spawner = WaveSpawner::new(schedule(&content.enemies[0]));
+        let mut game = ConcreteGameModel::new(
+            content,
+            1,
+            spawner,
+            |_| SineTrajectory::new(0.0),
+            100,
+            20,
+            1.0,
+        );
+        game.set_interest(Some(Interest::new(10)));
+
+        let status = game.wave_status().unwrap();
+        assert_eq!(
+            (status.wave, status.total_waves, status.waves_cleared),
+            (1, 2, 0)
+        );
+        assert_eq!(status.until_next_wave, Some(Duration::from_secs(1)));
+
+        let mut spawned = 0;
+        let mut cleared = Vec::new();
+        for _ in 0..60 * 60 {
+            // Enemies die as soon as they show up, killing pays no reward here
+            kill_all(&mut game);
+            game.update(SIMULATION_STEP);
+
+            for event in game.drain_events() {
+                match event {
+                    GameEvent::EnemySpawned { .. } => spawned += 1,
+                    GameEvent::WaveEnded {
+                        wave,
+                        bonus,
+                        interest,
+                    } => {
+                        let status = game.wave_status().unwrap();
+                        cleared.push((wave, bonus, interest, status.waves_cleared));
+                        assert_eq!(game.wallet().balance(), [120, 152][wave - 1]);
+                    }
+                    _ => {}
+                }
+            }
+            if game.is_over() {
+                break;
+            }
+        }
+
+        assert_eq!(spawned, 3);
+        // Interest is paid on the balance before the bonus
+        assert_eq!(cleared, [(1, 10, 10, 1), (2, 20, 12, 2)]);
+        assert_eq!(game.outcome(), Some(GameOutcome::Victory));
+        let status = game.wave_status().unwrap();
+        assert_eq!(status.until_next_wave, None);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::event::GameEvent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum GameOutcome {
+    Victory,
+    Defeat,
+    Quit,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameStats {
+    #[serde(deserialize_with = "deserialize_by_name")]
+    enemies_killed: BTreeMap<&'static str, u64>,
+    #[serde(deserialize_with = "deserialize_by_name")]
+    damage_dealt: BTreeMap<&'static str, f32>,
+    enemies_leaked: u64,
+    lives_lost: u64,
+    money_earned: u64,
+    money_spent: u64,
+    #[serde(default)]
+    money_refunded: u64,
+    towers_built: u64,
+    time_survived: Duration,
+}
+
+impl GameStats {
+    pub fn enemies_killed(&self) -> &BTreeMap<&'static str, u64> {
+        &self.enemies_killed
+    }
+
+    pub fn total_enemies_killed(&self) -> u64 {
+        self.enemies_killed.values().sum()
+    }
+
+    /// Damage actually taken by enemies, keyed by the tower type which dealt it
+    pub fn damage_dealt(&self) -> &BTreeMap<&'static str, f32> {
+        &self.damage_dealt
+    }
+
+    pub fn enemies_leaked(&self) -> u64 {
+        self.enemies_leaked
+    }
+
+    pub fn lives_lost(&self) -> u64 {
+        self.lives_lost
+    }
+
+    pub fn money_earned(&self) -> u64 {
+        self.money_earned
+    }
+
+    pub fn money_spent(&self) -> u64 {
+        self.money_spent
+    }
+
+    /// Paid back for sold towers, kept apart from the money earned in the game
+    pub fn money_refunded(&self) -> u64 {
+        self.money_refunded
+    }
+
+    pub fn towers_built(&self) -> u64 {
+        self.towers_built
+    }
+
+    pub fn time_survived(&self) -> Duration {
+        self.time_survived
+    }
+
+    /// Statistics are collected from the events emitted by the game model
+    pub fn observe(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::EnemyDamaged {
+                amount,
+                source: Some(source),
+                ..
+            } => *self.damage_dealt.entry(source).or_default() += amount,
+            GameEvent::EnemyKilled { enemy, reward, .. } => {
+                *self.enemies_killed.entry(enemy.name).or_default() += 1;
+                self.money_earned = self.money_earned.saturating_add(reward);
+            }
+            GameEvent::EnemyLeaked { lives_lost, .. } => {
+                self.enemies_leaked += 1;
+                self.lives_lost = self.lives_lost.saturating_add(lives_lost);
+            }
+            GameEvent::WaveEnded {
+                bonus, interest, ..
+            } => {
+                self.money_earned = self
+                    .money_earned
+                    .saturating_add(bonus)
+                    .saturating_add(interest);
+            }
+            GameEvent::TowerBuilt { cost, .. } => {
+                self.towers_built += 1;
+                self.money_spent = self.money_spent.saturating_add(cost);
+            }
+            GameEvent::TowerUpgraded { cost, .. } => {
+                self.money_spent = self.money_spent.saturating_add(cost);
+            }
+            GameEvent::TowerSold { refund, .. } => {
+                self.money_refunded = self.money_refunded.saturating_add(refund);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn record_time(&mut self, delta_time: Duration) {
+        self.time_survived += delta_time;
+    }
+}
+
+/// Type names are leaked the same way loaded content is, so stats restored
+/// from a save can keep using `&'static str` keys
+fn deserialize_by_name<'de, D, V>(deserializer: D) -> Result<BTreeMap<&'static str, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    let values = BTreeMap::<String, V>::deserialize(deserializer)?;
+    Ok(values
+        .into_iter()
+        .map(|(name, value)| (&*Box::leak(name.into_boxed_str()), value))
+        .collect())
+}
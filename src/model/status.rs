@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::damage::Damage;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum StatusEffectKind {
+    Slow(f32),
+    Burn(Damage),
+    Stun,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub duration: Duration,
+    /// Tower type which applied the effect, damage over time is credited to it.
+    /// Not saved, so burns restored from a save are not credited to anyone
+    #[serde(skip)]
+    pub source: Option<&'static str>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct StatusEffects {
+    active: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    const MAX_BURN_STACKS: usize = 3;
+
+    pub fn apply(&mut self, effect: StatusEffect) {
+        match effect.kind {
+            StatusEffectKind::Slow(factor) => {
+                let slow = self.active.iter_mut().find_map(|active| match active.kind {
+                    StatusEffectKind::Slow(active_factor) => Some((active, active_factor)),
+                    _ => None,
+                });
+                match slow {
+                    Some((active, active_factor)) => {
+                        active.kind = StatusEffectKind::Slow(active_factor.min(factor));
+                        active.duration = active.duration.max(effect.duration);
+                    }
+                    None => self.active.push(effect),
+                }
+            }
+            StatusEffectKind::Burn(_) => {
+                if self.burn_stacks() < Self::MAX_BURN_STACKS {
+                    self.active.push(effect);
+                    return;
+                }
+                let shortest = self
+                    .active
+                    .iter_mut()
+                    .filter(|active| matches!(active.kind, StatusEffectKind::Burn(_)))
+                    .min_by_key(|active| active.duration);
+                if let Some(shortest) = shortest {
+                    *shortest = effect;
+                }
+            }
+            StatusEffectKind::Stun => {
+                match self
+                    .active
+                    .iter_mut()
+                    .find(|active| active.kind == StatusEffectKind::Stun)
+                {
+                    Some(active) => active.duration = active.duration.max(effect.duration),
+                    None => self.active.push(effect),
+                }
+            }
+        }
+    }
+
+    pub fn tick(&mut self, delta_time: Duration) -> Vec<(Damage, Option<&'static str>)> {
+        let damages = self
+            .active
+            .iter()
+            .filter_map(|active| match active.kind {
+                StatusEffectKind::Burn(damage) => Some((
+                    Damage {
+                        value: damage.value * active.duration.min(delta_time).as_secs_f32(),
+                        kind: damage.kind,
+                    },
+                    active.source,
+                )),
+                _ => None,
+            })
+            .collect();
+
+        for active in self.active.iter_mut() {
+            active.duration = active.duration.saturating_sub(delta_time);
+        }
+        self.active.retain(|active| !active.duration.is_zero());
+        damages
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_stunned() {
+            return 0.0;
+        }
+        self.active
+            .iter()
+            .filter_map(|active| match active.kind {
+                StatusEffectKind::Slow(factor) => Some(factor),
+                _ => None,
+            })
+            .fold(1.0, f32::min)
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.active
+            .iter()
+            .any(|active| active.kind == StatusEffectKind::Stun)
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        self.active
+            .iter()
+            .any(|active| matches!(active.kind, StatusEffectKind::Slow(_)))
+    }
+
+    pub fn is_burning(&self) -> bool {
+        self.burn_stacks() > 0
+    }
+
+    fn burn_stacks(&self) -> usize {
+        self.active
+            .iter()
+            .filter(|active| matches!(active.kind, StatusEffectKind::Burn(_)))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DamageType;
+
+    fn effect(kind: StatusEffectKind, duration_ms: u64) -> StatusEffect {
+        StatusEffect {
+            kind,
+            duration: Duration::from_millis(duration_ms),
+            source: None,
+        }
+    }
+
+    fn burn(value: f32, duration_ms: u64) -> StatusEffect {
+        let damage = Damage {
+            value,
+            kind: DamageType::Magic,
+        };
+        effect(StatusEffectKind::Burn(damage), duration_ms)
+    }
+
+    #[test]
+    fn slow_keeps_strongest_factor_and_longest_duration() {
+        let mut effects = StatusEffects::default();
+        effects.apply(effect(StatusEffectKind::Slow(0.5), 1000));
+        effects.apply(effect(StatusEffectKind::Slow(0.8), 3000));
+
+        assert_eq!(
+            effects.active,
+            vec![effect(StatusEffectKind::Slow(0.5), 3000)]
+        );
+        assert_eq!(effects.speed_multiplier(), 0.5);
+    }
+
+    #[test]
+    fn burn_stacks_are_capped_and_replace_the_shortest() {
+        let mut effects = StatusEffects::default();
+        for duration_ms in [2000, 1000, 3000] {
+            effects.apply(burn(1.0, duration_ms));
+        }
+        effects.apply(burn(5.0, 500));
+
+        assert_eq!(effects.burn_stacks(), StatusEffects::MAX_BURN_STACKS);
+        assert_eq!(
+            effects.active,
+            vec![burn(1.0, 2000), burn(5.0, 500), burn(1.0, 3000)]
+        );
+    }
+
+    #[test]
+    fn stun_keeps_longest_duration() {
+        let mut effects = StatusEffects::default();
+        effects.apply(effect(StatusEffectKind::Stun, 2000));
+        effects.apply(effect(StatusEffectKind::Stun, 500));
+
+        assert_eq!(effects.active, vec![effect(StatusEffectKind::Stun, 2000)]);
+        assert_eq!(effects.speed_multiplier(), 0.0);
+    }
+}
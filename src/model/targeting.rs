@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{damage::Damage, enemy::Enemy};
+
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Targeting {
+    #[default]
+    First,
+    Last,
+    Strongest,
+    Weakest,
+    Closest,
+    BestDamage,
+}
+
+impl Targeting {
+    const ALL: &'static [Targeting] = &[
+        Targeting::First,
+        Targeting::Last,
+        Targeting::Strongest,
+        Targeting::Weakest,
+        Targeting::Closest,
+        Targeting::BestDamage,
+    ];
+
+    pub fn next(&self) -> Self {
+        let idx = Self::ALL.iter().position(|opt| opt == self).unwrap();
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Higher score means more preferable target.
+    pub fn score(&self, enemy: &Enemy, distance: f32, damage: Damage) -> f32 {
+        match self {
+            Targeting::First => enemy.t_position(),
+            Targeting::Last => -enemy.t_position(),
+            Targeting::Strongest => enemy.health(),
+            Targeting::Weakest => -enemy.health(),
+            Targeting::Closest => -distance,
+            Targeting::BestDamage => enemy.evaluate_damage(damage),
+        }
+    }
+}
+
+impl Display for Targeting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Targeting::First => "First",
+            Targeting::Last => "Last",
+            Targeting::Strongest => "Strongest",
+            Targeting::Weakest => "Weakest",
+            Targeting::Closest => "Closest",
+            Targeting::BestDamage => "Best damage",
+        };
+        write!(f, "{}", text)
+    }
+}
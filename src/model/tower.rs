@@ -1,56 +1,76 @@
-use std::time::Duration;
+use std::{error::Error, fmt::Display, time::Duration};
 
-use rand::seq::IteratorRandom;
+use serde::Deserialize;
 
 use super::{
-    core::{EnemyShared, GameModel, UpdatableObject},
+    core::{EnemyId, GameModel, UpdatableObject},
     damage::Damage,
+    enemy::Enemy,
+    event::GameEvent,
+    explosion::Explosion,
     point::{Point, Positioned},
-    trajectory::Trajectory,
+    snapshot::{ProjectileSnapshot, SnapshotErr, TowerSnapshot},
+    status::StatusEffect,
+    targeting::Targeting,
 };
-use crate::ui::PointDrawInfo;
+use crate::{content::Content, ui::PointDrawInfo};
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 struct Aim {
-    aim: Option<EnemyShared>,
+    aim: Option<EnemyId>,
 }
 
 impl Aim {
-    pub fn new(aim: Option<EnemyShared>) -> Self {
-        Self { aim: aim }
+    pub fn new(aim: Option<EnemyId>) -> Self {
+        Self { aim }
     }
 
-    pub fn aim(&self) -> &Option<EnemyShared> {
-        &self.aim
+    pub fn aim(&self) -> Option<EnemyId> {
+        self.aim
     }
 
-    pub fn is_in_shoot_range(&self, tower: &Tower, trajectory: &dyn Trajectory) -> bool {
-        match self.aim.as_ref() {
-            Some(aimcell) => {
-                let aim = aimcell;
-                let enemypos = trajectory.point_from_t(aim.borrow().t_position());
-                enemypos.distance(tower.position()) < tower.range()
-            }
-            None => false,
-        }
+    /// Returns the aimed enemy unless it is dead or already removed
+    pub fn target<'a>(&self, game_model: &'a impl GameModel) -> Option<&'a Enemy> {
+        self.aim
+            .and_then(|id| game_model.enemy(id))
+            .filter(|enemy| !enemy.is_dead())
     }
 
-    pub fn is_alive(&self) -> bool {
-        self.aim.is_some() && !self.aim.as_ref().unwrap().borrow().is_dead()
+    fn target_mut<'a>(&self, game_model: &'a mut impl GameModel) -> Option<&'a mut Enemy> {
+        self.aim
+            .and_then(|id| game_model.enemy_mut(id))
+            .filter(|enemy| !enemy.is_dead())
     }
 
-    pub fn try_damage(&mut self, damage: Damage, on_death: impl FnOnce(u64)) {
-        if !self.is_some() {
+    pub fn try_damage(
+        &mut self,
+        game_model: &mut impl GameModel,
+        damage: Damage,
+        source: &'static str,
+    ) {
+        let (Some(id), Some(enemy)) = (self.aim, self.target_mut(game_model)) else {
+            self.aim = None;
             return;
-        }
-        let aim = self.aim.as_ref().unwrap();
-        aim.borrow_mut().take_damage(damage);
-        if aim.borrow().is_dead() {
-            on_death(aim.borrow().reward());
+        };
+        let amount = enemy.take_damage(damage);
+        let killed = enemy.is_dead().then(|| enemy.type_info());
+        game_model.emit(GameEvent::EnemyDamaged {
+            id,
+            amount,
+            source: Some(source),
+        });
+        if let Some(enemy) = killed {
+            game_model.reward_kill(id, enemy);
             self.aim = None;
         }
     }
 
+    pub fn try_apply_effect(&self, game_model: &mut impl GameModel, effect: StatusEffect) {
+        if let Some(enemy) = self.target_mut(game_model) {
+            enemy.apply_effect(effect);
+        }
+    }
+
     pub fn is_some(&self) -> bool {
         self.aim.is_some()
     }
@@ -65,13 +85,48 @@ pub struct TowerInfo {
     pub description: &'static str,
     pub projectile_info: ProjectileInfo,
     pub draw_info: PointDrawInfo,
+    pub upgrades: &'static [TowerUpgrade],
+}
+
+impl TowerInfo {
+    /// Level 0 is the base tower, every upgrade adds one
+    fn projectile_info_at(&self, level: usize) -> Option<&ProjectileInfo> {
+        match level.checked_sub(1) {
+            Some(idx) => self
+                .upgrades
+                .get(idx)
+                .map(|upgrade| &upgrade.projectile_info),
+            None => Some(&self.projectile_info),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TowerUpgrade {
+    pub cost: u64,
+    pub cooldown: Duration,
+    pub range: f32,
+    pub projectile_info: ProjectileInfo,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxTowerLevelErr {}
+
+impl Display for MaxTowerLevelErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tried to upgrade tower which is already at max level")
+    }
+}
+
+impl Error for MaxTowerLevelErr {}
+
 #[derive(Debug, Clone)]
 pub struct Tower {
     aim: Aim,
     position: Point,
     cooldown_elapsed: Duration,
+    level: usize,
+    targeting: Targeting,
     type_info: &'static TowerInfo,
 }
 
@@ -79,9 +134,11 @@ impl Tower {
     pub fn new(position: Point, type_info: &'static TowerInfo) -> Self {
         Self {
             aim: Aim::new(None),
-            position: position,
+            position,
             cooldown_elapsed: Duration::from_millis(0),
-            type_info: type_info,
+            level: 0,
+            targeting: Targeting::default(),
+            type_info,
         }
     }
 
@@ -89,12 +146,105 @@ impl Tower {
         self.type_info.cost
     }
 
+    pub fn invested(&self) -> u64 {
+        self.type_info.upgrades[..self.level]
+            .iter()
+            .map(|upgrade| upgrade.cost)
+            .sum::<u64>()
+            + self.type_info.cost
+    }
+
+    /// Level shown to the player, counted from 1
+    pub fn display_level(&self) -> usize {
+        self.level + 1
+    }
+
+    /// Highest level shown to the player, counted from 1
+    pub fn max_display_level(&self) -> usize {
+        self.type_info.upgrades.len() + 1
+    }
+
     pub fn range(&self) -> f32 {
-        self.type_info.range
+        match self.current_upgrade() {
+            Some(upgrade) => upgrade.range,
+            None => self.type_info.range,
+        }
+    }
+
+    pub fn cooldown(&self) -> Duration {
+        match self.current_upgrade() {
+            Some(upgrade) => upgrade.cooldown,
+            None => self.type_info.cooldown,
+        }
+    }
+
+    pub fn projectile_info(&self) -> &'static ProjectileInfo {
+        match self.current_upgrade() {
+            Some(upgrade) => &upgrade.projectile_info,
+            None => &self.type_info.projectile_info,
+        }
+    }
+
+    pub fn targeting(&self) -> Targeting {
+        self.targeting
+    }
+
+    pub fn set_targeting(&mut self, targeting: Targeting) -> &mut Self {
+        self.targeting = targeting;
+        self
+    }
+
+    pub fn next_upgrade(&self) -> Option<&'static TowerUpgrade> {
+        self.type_info.upgrades.get(self.level)
+    }
+
+    pub fn upgrade(&mut self) -> Result<(), MaxTowerLevelErr> {
+        self.next_upgrade().ok_or(MaxTowerLevelErr {})?;
+        self.level += 1;
+        Ok(())
     }
 
     pub fn type_info(&self) -> &'static TowerInfo {
-        &self.type_info
+        self.type_info
+    }
+
+    pub fn snapshot(&self) -> TowerSnapshot {
+        TowerSnapshot {
+            type_name: self.type_info.name.to_owned(),
+            position: self.position,
+            cooldown_elapsed: self.cooldown_elapsed,
+            level: self.level,
+            targeting: self.targeting,
+            aim: self.aim.aim(),
+        }
+    }
+
+    pub fn restore(snapshot: TowerSnapshot, content: Content) -> Result<Self, SnapshotErr> {
+        let type_info = content
+            .tower(&snapshot.type_name)
+            .ok_or_else(|| SnapshotErr::UnknownTower(snapshot.type_name.clone()))?;
+        if snapshot.level > type_info.upgrades.len() {
+            return Err(SnapshotErr::UnknownTowerLevel(
+                snapshot.type_name,
+                snapshot.level,
+            ));
+        }
+        Ok(Self {
+            aim: Aim::new(snapshot.aim),
+            position: snapshot.position,
+            cooldown_elapsed: snapshot.cooldown_elapsed,
+            level: snapshot.level,
+            targeting: snapshot.targeting,
+            type_info,
+        })
+    }
+}
+
+impl Tower {
+    fn current_upgrade(&self) -> Option<&'static TowerUpgrade> {
+        self.level
+            .checked_sub(1)
+            .and_then(|idx| self.type_info.upgrades.get(idx))
     }
 }
 
@@ -108,7 +258,7 @@ impl UpdatableObject for Tower {
     fn on_update(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
         self.update_aim(game_model);
         self.cooldown_elapsed += delta_time;
-        if self.cooldown_elapsed >= self.type_info.cooldown {
+        if self.cooldown_elapsed >= self.cooldown() {
             self.maybe_shoot(game_model);
             self.cooldown_elapsed = Duration::from_millis(0);
         }
@@ -117,41 +267,46 @@ impl UpdatableObject for Tower {
 
 impl Tower {
     fn maybe_shoot(&mut self, game_model: &mut impl GameModel) {
-        if !self.aim.is_some() {
+        let Some(target) = self.aim.aim() else {
             return;
-        }
+        };
 
         let projectile = Projectile::new(
             self.position,
-            self.aim.aim().as_ref().unwrap().clone(),
-            &self.type_info.projectile_info,
+            target,
+            self.projectile_info(),
+            self.type_info.name,
+            self.level,
         );
 
         game_model.spawn_projectile(projectile);
+        game_model.emit(GameEvent::ProjectileFired {
+            source: self.type_info.name,
+            position: self.position,
+            target,
+        });
     }
 
     fn update_aim(&mut self, game_model: &impl GameModel) {
-        if !self.aim.is_in_shoot_range(self, game_model.trajectory()) || !self.aim.is_alive() {
-            self.aim = Aim::new(None);
-        }
-
-        if self.aim.is_some() {
-            return;
-        }
+        let damage = self.projectile_info().damage;
+        let trajectory = game_model.trajectory();
 
-        let random_chosen_enemy = game_model
+        let best_enemy = game_model
             .enemies()
-            .iter()
-            .filter(|enemy| {
-                let enemypos = game_model
-                    .trajectory()
-                    .point_from_t(enemy.borrow().t_position());
-                enemypos.distance(self.position()) < self.range()
+            .filter(|(_, enemy)| !enemy.is_dead())
+            .filter_map(|(id, enemy)| {
+                let enemypos = trajectory.point_from_t(enemy.t_position());
+                let distance = enemypos.distance(self.position());
+                (distance < self.range()).then_some((id, enemy, distance))
+            })
+            .max_by(|(_, lhs, lhs_distance), (_, rhs, rhs_distance)| {
+                let lhs_score = self.targeting.score(lhs, *lhs_distance, damage);
+                let rhs_score = self.targeting.score(rhs, *rhs_distance, damage);
+                lhs_score.total_cmp(&rhs_score)
             })
-            .map(|rc| rc.clone())
-            .choose(&mut rand::thread_rng());
+            .map(|(id, _, _)| id);
 
-        self.aim = Aim::new(random_chosen_enemy);
+        self.aim = Aim::new(best_enemy);
     }
 }
 
@@ -159,6 +314,25 @@ impl Tower {
 pub struct ProjectileInfo {
     pub speed: f32,
     pub damage: Damage,
+    pub effect: Option<StatusEffect>,
+    pub splash: Option<Splash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Splash {
+    pub radius: f32,
+    pub falloff: f32,
+}
+
+impl Splash {
+    pub fn damage_at(&self, damage: Damage, distance: f32) -> Damage {
+        let coef = 1.0 - self.falloff * (distance / self.radius);
+        Damage {
+            value: damage.value * coef.clamp(0.0, 1.0),
+            kind: damage.kind,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -166,20 +340,74 @@ pub struct Projectile {
     position: Point,
     aim: Aim,
     type_info: &'static ProjectileInfo,
+    source: &'static str,
+    /// Level of the source tower when it fired, counted from 0
+    level: usize,
 }
 
 impl Projectile {
-    fn new(position: Point, aim: EnemyShared, type_info: &'static ProjectileInfo) -> Self {
+    fn new(
+        position: Point,
+        aim: EnemyId,
+        type_info: &'static ProjectileInfo,
+        source: &'static str,
+        level: usize,
+    ) -> Self {
         Self {
-            position: position,
+            position,
             aim: Aim::new(Some(aim)),
-            type_info: type_info,
+            type_info,
+            source,
+            level,
         }
     }
 
     pub fn type_info(&self) -> &'static ProjectileInfo {
         self.type_info
     }
+
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Fails if the source tower level isn't in content, the save couldn't be restored
+    pub fn snapshot(&self, content: Content) -> Result<ProjectileSnapshot, SnapshotErr> {
+        let tower = content
+            .tower(self.source)
+            .ok_or_else(|| SnapshotErr::UnknownTower(self.source.to_owned()))?;
+        if tower.projectile_info_at(self.level).is_none() {
+            return Err(SnapshotErr::UnknownTowerLevel(
+                self.source.to_owned(),
+                self.level,
+            ));
+        }
+        Ok(ProjectileSnapshot {
+            tower_name: self.source.to_owned(),
+            level: self.level,
+            position: self.position,
+            aim: self.aim.aim(),
+        })
+    }
+
+    pub fn restore(snapshot: ProjectileSnapshot, content: Content) -> Result<Self, SnapshotErr> {
+        let tower = content
+            .tower(&snapshot.tower_name)
+            .ok_or_else(|| SnapshotErr::UnknownTower(snapshot.tower_name.clone()))?;
+        let type_info =
+            tower
+                .projectile_info_at(snapshot.level)
+                .ok_or(SnapshotErr::UnknownTowerLevel(
+                    snapshot.tower_name,
+                    snapshot.level,
+                ))?;
+        Ok(Self {
+            position: snapshot.position,
+            aim: Aim::new(snapshot.aim),
+            type_info,
+            source: tower.name,
+            level: snapshot.level,
+        })
+    }
 }
 
 impl Positioned for Projectile {
@@ -200,17 +428,18 @@ impl Projectile {
     }
 
     pub fn move_to_aim(&mut self, game_model: &mut impl GameModel, delta_time: Duration) {
-        if !self.is_active() {
+        let Some(t) = self.aim.target(game_model).map(|enemy| enemy.t_position()) else {
+            self.aim = Aim::new(None);
             return;
-        }
+        };
         let move_points = self.type_info.speed * delta_time.as_secs_f32();
         let trajectory = game_model.trajectory();
-        let t = self.aim.aim().as_ref().unwrap().borrow().t_position();
         let aim_pos = trajectory.point_from_t(t);
         let direction = (aim_pos - self.position).normalize();
         let distance = self.position.distance(aim_pos);
 
         if distance < move_points {
+            self.position = aim_pos;
             self.on_collision(game_model);
             return;
         }
@@ -218,9 +447,117 @@ impl Projectile {
     }
 
     fn on_collision(&mut self, game_model: &mut impl GameModel) {
-        self.aim.try_damage(self.type_info.damage, |reward| {
-            game_model.wallet_mut().add_money(reward);
-        });
+        match self.type_info.splash {
+            Some(splash) => self.explode(game_model, splash),
+            None => Self::hit(
+                &mut self.aim,
+                game_model,
+                self.type_info.damage,
+                self.type_info,
+                self.source,
+            ),
+        }
         self.aim = Aim::new(None);
     }
+
+    fn explode(&mut self, game_model: &mut impl GameModel, splash: Splash) {
+        let trajectory = game_model.trajectory();
+        let in_radius: Vec<_> = game_model
+            .enemies()
+            .filter(|(_, enemy)| !enemy.is_dead())
+            .filter_map(|(id, enemy)| {
+                let enemypos = trajectory.point_from_t(enemy.t_position());
+                let distance = enemypos.distance(self.position);
+                (distance <= splash.radius).then_some((id, distance))
+            })
+            .collect();
+
+        for (enemy, distance) in in_radius {
+            let damage = splash.damage_at(self.type_info.damage, distance);
+            Self::hit(
+                &mut Aim::new(Some(enemy)),
+                game_model,
+                damage,
+                self.type_info,
+                self.source,
+            );
+        }
+        game_model.spawn_explosion(Explosion::new(self.position, splash.radius));
+    }
+
+    fn hit(
+        aim: &mut Aim,
+        game_model: &mut impl GameModel,
+        damage: Damage,
+        type_info: &ProjectileInfo,
+        source: &'static str,
+    ) {
+        if let Some(effect) = type_info.effect {
+            aim.try_apply_effect(
+                game_model,
+                StatusEffect {
+                    source: Some(source),
+                    ..effect
+                },
+            );
+        }
+        aim.try_damage(game_model, damage, source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ConcreteGameModel, DamageType, SineTrajectory, WaveSpawner};
+
+    fn game() -> ConcreteGameModel<WaveSpawner, SineTrajectory> {
+        let content = Content::builtin();
+        ConcreteGameModel::new(
+            content,
+            1,
+            WaveSpawner::new(content.waves),
+            |_| SineTrajectory::new(0.0),
+            0,
+            20,
+            1.0,
+        )
+    }
+
+    fn tower_next_to(game: &impl GameModel, t: f32) -> Tower {
+        let road = game.trajectory().point_from_t(t);
+        let position = Point {
+            x: road.x,
+            y: road.y + 2.0,
+        };
+        Tower::new(position, &game.content().towers[0])
+    }
+
+    #[test]
+    fn fires_at_living_enemy_in_range() {
+        let mut game = game();
+        let enemy = &game.content().enemies[0];
+        game.spawn_enemy(enemy);
+
+        let mut tower = tower_next_to(&game, 0.0);
+        let cooldown = tower.cooldown();
+        tower.on_update(&mut game, cooldown);
+        assert_eq!(game.projectiles().len(), 1);
+    }
+
+    #[test]
+    fn does_not_fire_at_enemy_killed_earlier_in_the_tick() {
+        let mut game = game();
+        let enemy = &game.content().enemies[0];
+        let id = game.spawn_enemy(enemy);
+        let killing_blow = Damage {
+            value: enemy.max_health * 10.0,
+            kind: DamageType::Magic,
+        };
+        game.enemy_mut(id).unwrap().take_damage(killing_blow);
+
+        let mut tower = tower_next_to(&game, 0.0);
+        let cooldown = tower.cooldown();
+        tower.on_update(&mut game, cooldown);
+        assert!(game.projectiles().is_empty());
+    }
 }
@@ -1,4 +1,5 @@
-use noise::NoiseFn;
+use noise::{NoiseFn, Perlin, Seedable};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::point::Point;
 
@@ -6,7 +7,44 @@ pub trait Trajectory {
     fn point_from_t(&self, t: f32) -> Point;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Trajectory sampled once between `0` and `max_t`, so distance checks don't
+/// evaluate the curve again
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Polyline {
+    points: Vec<Point>,
+}
+
+impl Polyline {
+    const SAMPLE_STEP: f32 = 0.1;
+
+    pub fn sample(trajectory: &impl Trajectory, max_t: f32) -> Self {
+        let samples = (max_t / Self::SAMPLE_STEP).ceil() as usize;
+        let points = (0..=samples)
+            .map(|idx| (idx as f32 * Self::SAMPLE_STEP).min(max_t))
+            .map(|t| trajectory.point_from_t(t))
+            .collect();
+        Self { points }
+    }
+
+    /// Distance from the point to the closest segment
+    pub fn distance_to(&self, point: Point) -> f32 {
+        self.points
+            .windows(2)
+            .map(|segment| {
+                let (start, end) = (segment[0], segment[1]);
+                let along = end - start;
+                let len_sq = along.dot(along);
+                if len_sq == 0.0 {
+                    return start.distance(point);
+                }
+                let coef = ((point - start).dot(along) / len_sq).clamp(0.0, 1.0);
+                (start + along * coef).distance(point)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NoiseTrajectory<Noise: NoiseFn<f64, 2>> {
     gen: Noise,
 }
@@ -27,3 +65,53 @@ impl<Noise: NoiseFn<f64, 2>> Trajectory for NoiseTrajectory<Noise> {
         }
     }
 }
+
+/// Perlin noise is fully determined by its seed, so only the seed is saved
+impl Serialize for NoiseTrajectory<Perlin> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.gen.seed().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NoiseTrajectory<Perlin> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(|seed| Self::new(Perlin::new(seed)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SineTrajectory {
+    phase: f32,
+}
+
+impl SineTrajectory {
+    pub fn new(phase: f32) -> Self {
+        Self { phase }
+    }
+}
+
+impl Trajectory for SineTrajectory {
+    fn point_from_t(&self, t: f32) -> Point {
+        const WAVE_L: f32 = 8.0;
+        const AMPLITUDE: f32 = 5.0;
+        Point {
+            x: t,
+            y: (t / WAVE_L + self.phase).sin() * AMPLITUDE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AnyTrajectory {
+    Noise(Box<NoiseTrajectory<Perlin>>),
+    Sine(SineTrajectory),
+}
+
+impl Trajectory for AnyTrajectory {
+    fn point_from_t(&self, t: f32) -> Point {
+        match self {
+            AnyTrajectory::Noise(trajectory) => trajectory.point_from_t(t),
+            AnyTrajectory::Sine(trajectory) => trajectory.point_from_t(t),
+        }
+    }
+}
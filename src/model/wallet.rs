@@ -1,6 +1,10 @@
 use std::{error::Error, fmt::Display};
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 pub struct Wallet {
     balance: u64,
 }
@@ -26,7 +30,7 @@ impl Wallet {
     }
 
     pub fn add_money(&mut self, to_add: u64) -> &Self {
-        self.balance = self.balance.checked_add(to_add).unwrap_or(u64::MAX);
+        self.balance = self.balance.saturating_add(to_add);
         self
     }
 
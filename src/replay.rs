@@ -0,0 +1,212 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::GameConfig, content::Content, input::InputEvent, input::ScreenInfo, model::Point,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReplayErr {
+    ContentChanged(PathBuf),
+}
+
+impl Display for ReplayErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayErr::ContentChanged(path) => write!(
+                f,
+                "Content file {} changed since the replay was recorded",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for ReplayErr {}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    pub tick: u64,
+    pub event: InputEvent,
+    pub screen_info: ScreenInfo,
+    /// Cursor before the event, mouse movement itself isn't recorded
+    #[serde(default)]
+    pub cursor: Point,
+    pub affects_model: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Replay {
+    config: GameConfig,
+    content: Option<PathBuf>,
+    /// Hash of the content file, replays only play back with the same content
+    #[serde(default)]
+    content_hash: Option<u64>,
+    entries: Vec<ReplayEntry>,
+    last_tick: u64,
+}
+
+impl Replay {
+    pub fn new(config: GameConfig, content: Option<PathBuf>, content_hash: Option<u64>) -> Self {
+        Self {
+            config,
+            content,
+            content_hash,
+            entries: Vec::new(),
+            last_tick: 0,
+        }
+    }
+
+    /// First of `path`, `path-2`, `path-3`... that doesn't exist yet, so
+    /// recordings never overwrite each other
+    pub fn unused_path(path: &Path) -> PathBuf {
+        let mut candidate = path.to_path_buf();
+        let mut number = 1;
+        while candidate.exists() {
+            number += 1;
+            candidate = numbered_path(path, number);
+        }
+        candidate
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.config
+    }
+
+    pub fn content(&self) -> Option<&Path> {
+        self.content.as_deref()
+    }
+
+    /// Fails when the content file differs from the one the replay was recorded with
+    pub fn check_content(&self) -> Result<(), Box<dyn Error>> {
+        let Some(path) = &self.content else {
+            return Ok(());
+        };
+        if Some(Content::file_hash(path)?) != self.content_hash {
+            return Err(ReplayErr::ContentChanged(path.clone()).into());
+        }
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[ReplayEntry] {
+        &self.entries
+    }
+
+    pub fn last_tick(&self) -> u64 {
+        self.last_tick
+    }
+
+    pub fn record(&mut self, entry: ReplayEntry) {
+        self.last_tick = self.last_tick.max(entry.tick);
+        self.entries.push(entry);
+    }
+
+    pub fn finish(&mut self, tick: u64) {
+        self.last_tick = self.last_tick.max(tick);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ReplayPlayer {
+    replay: Replay,
+    next_entry: usize,
+    speed: u32,
+    paused: bool,
+}
+
+impl ReplayPlayer {
+    const MAX_SPEED: u32 = 16;
+
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            next_entry: 0,
+            speed: 1,
+            paused: false,
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    pub fn speed(&self) -> u32 {
+        self.speed
+    }
+
+    pub fn speed_up(&mut self) {
+        self.speed = (self.speed * 2).min(Self::MAX_SPEED);
+    }
+
+    pub fn slow_down(&mut self) {
+        self.speed = (self.speed / 2).max(1);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn switch_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn is_finished(&self, tick: u64) -> bool {
+        tick >= self.replay.last_tick
+    }
+
+    pub fn rewind(&mut self) {
+        self.next_entry = 0;
+    }
+
+    pub fn take_due(&mut self, tick: u64) -> &[ReplayEntry] {
+        let entries = &self.replay.entries[self.next_entry..];
+        let due = entries
+            .iter()
+            .take_while(|entry| entry.tick <= tick)
+            .count();
+        self.next_entry += due;
+        &entries[..due]
+    }
+}
+
+/// `game.json` becomes `game-2.json`, the extension is kept
+fn numbered_path(path: &Path, number: u32) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, number, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, number),
+    };
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_path_keeps_directory_and_extension() {
+        assert_eq!(
+            numbered_path(Path::new("replays/game.json"), 2),
+            PathBuf::from("replays/game-2.json")
+        );
+        assert_eq!(numbered_path(Path::new("game"), 3), PathBuf::from("game-3"));
+    }
+}
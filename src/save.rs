@@ -0,0 +1,113 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Game,
+    content::Content,
+    model::{AnySpawner, AnyTrajectory, GameSnapshot, SnapshotErr},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveFile {
+    content: Option<PathBuf>,
+    game: GameSnapshot<AnySpawner, AnyTrajectory>,
+}
+
+impl SaveFile {
+    pub fn new(content: Option<PathBuf>, game: &Game) -> Result<Self, SnapshotErr> {
+        Ok(Self {
+            content,
+            game: game.snapshot()?,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    pub fn content(&self) -> Option<&Path> {
+        self.content.as_deref()
+    }
+
+    pub fn restore(self, content: Content) -> Result<Game, SnapshotErr> {
+        Game::restore(self.game, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{Difficulty, GameConfig, RoadKind, SpawnerKind},
+        model::{GameModel, Point, Tower, SIMULATION_STEP},
+    };
+
+    fn game_with_projectiles(content: Content) -> Game {
+        let config = GameConfig {
+            seed: 42,
+            road: RoadKind::Sine,
+            spawner: SpawnerKind::Waves,
+            difficulty: Difficulty::Easy,
+            initial_balance: Some(10_000),
+            interest: None,
+            road_clearance: None,
+            bounds: None,
+        };
+        let mut game = config.build_model(content).unwrap();
+
+        // One upgraded tower of every type next to the start of the road
+        for (idx, tower) in content.towers.iter().enumerate() {
+            let road = game.trajectory().point_from_t(0.05 * (idx + 1) as f32);
+            let position = (1..20)
+                .map(|dy| Point {
+                    x: road.x,
+                    y: road.y + dy as f32,
+                })
+                .find(|&position| game.check_placement(position).is_ok())
+                .expect("no free place near the road");
+            game.spawn_tower(Tower::new(position, tower)).unwrap();
+            game.select_tower(Some(idx));
+            game.upgrade_selected_tower().unwrap();
+        }
+        game.select_tower(None);
+
+        for _ in 0..60 * 60 {
+            game.update(SIMULATION_STEP);
+            if game.projectiles().len() >= 2 {
+                return game;
+            }
+        }
+        panic!("towers didn't fire");
+    }
+
+    fn save_json(game: &Game) -> String {
+        serde_json::to_string(&SaveFile::new(None, game).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn round_trip_keeps_projectiles_in_flight() {
+        let content = Content::builtin();
+        let game = game_with_projectiles(content);
+        let saved = save_json(&game);
+
+        let restored = serde_json::from_str::<SaveFile>(&saved)
+            .unwrap()
+            .restore(content)
+            .unwrap();
+        assert_eq!(restored.projectiles().len(), game.projectiles().len());
+        assert_eq!(save_json(&restored), saved);
+    }
+}
@@ -0,0 +1,366 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display, fs, io, path::Path, time::Duration};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Game, GameConfig},
+    content::Content,
+    model::{
+        GameModel, GameOutcome, NotEnoughMoneyErr, Point, SettingErr, Targeting, Tower,
+        SIMULATION_STEP,
+    },
+};
+
+#[derive(Debug)]
+pub enum ScriptErr {
+    Read(String, io::Error),
+    Parse(String, toml::de::Error),
+    UnknownTower(String),
+    InvalidTime(&'static str, f32),
+}
+
+impl Display for ScriptErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptErr::Read(path, err) => {
+                write!(f, "Failed to read simulation script {}: {}", path, err)
+            }
+            ScriptErr::Parse(path, err) => {
+                write!(f, "Failed to parse simulation script {}: {}", path, err)
+            }
+            ScriptErr::UnknownTower(name) => {
+                write!(f, "Simulation script builds unknown tower type '{}'", name)
+            }
+            ScriptErr::InvalidTime(field, value) => {
+                write!(
+                    f,
+                    "Simulation script has invalid {} {} seconds",
+                    field, value
+                )
+            }
+        }
+    }
+}
+
+impl Error for ScriptErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScriptErr::Read(_, err) => Some(err),
+            ScriptErr::Parse(_, err) => Some(err),
+            ScriptErr::UnknownTower(_) | ScriptErr::InvalidTime(_, _) => None,
+        }
+    }
+}
+
+/// Orders are carried out one by one, each waits for its time and until it is affordable
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Order {
+    Build {
+        #[serde(default)]
+        at: f32,
+        tower: String,
+        x: f32,
+        y: f32,
+        #[serde(default)]
+        targeting: Targeting,
+    },
+    Upgrade {
+        #[serde(default)]
+        at: f32,
+        x: f32,
+        y: f32,
+    },
+}
+
+impl Order {
+    fn at_secs(&self) -> f32 {
+        let (Order::Build { at, .. } | Order::Upgrade { at, .. }) = self;
+        *at
+    }
+
+    fn at(&self) -> Duration {
+        Duration::from_secs_f32(self.at_secs().max(0.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum OrderResult {
+    Done,
+    Failed,
+    Pending,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SimScript {
+    /// Simulated seconds after which an unfinished run is stopped
+    #[serde(default = "SimScript::default_time_limit")]
+    time_limit: f32,
+    /// Simulated seconds between gold curve samples
+    #[serde(default = "SimScript::default_sample_interval")]
+    sample_interval: f32,
+    #[serde(default)]
+    orders: Vec<Order>,
+}
+
+impl SimScript {
+    fn default_time_limit() -> f32 {
+        30.0 * 60.0
+    }
+
+    fn default_sample_interval() -> f32 {
+        10.0
+    }
+
+    pub fn load(path: &Path, content: Content) -> Result<Self, ScriptErr> {
+        let source = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|err| ScriptErr::Read(source.clone(), err))?;
+        let script: SimScript =
+            toml::from_str(&text).map_err(|err| ScriptErr::Parse(source, err))?;
+
+        check_time("time_limit", script.time_limit)?;
+        check_time("sample_interval", script.sample_interval)?;
+        for order in script.orders.iter() {
+            check_time("order time", order.at_secs())?;
+            if let Order::Build { tower, .. } = order {
+                if content.tower(tower).is_none() {
+                    return Err(ScriptErr::UnknownTower(tower.clone()));
+                }
+            }
+        }
+        Ok(script)
+    }
+
+    pub fn run(&self, config: GameConfig, content: Content) -> Result<RunReport, SettingErr> {
+        let mut model = config.build_model(content)?;
+        let time_limit = Duration::from_secs_f32(self.time_limit.max(0.0));
+        let sample_interval = Duration::from_secs_f32(self.sample_interval.max(1.0));
+
+        let mut next_order = 0;
+        let mut orders_failed = 0;
+        let mut gold_curve = Vec::new();
+        let mut elapsed = Duration::from_millis(0);
+        let mut next_sample = Duration::from_millis(0);
+
+        while !model.is_over() && elapsed < time_limit {
+            while let Some(order) = self
+                .orders
+                .get(next_order)
+                .filter(|order| order.at() <= elapsed)
+            {
+                match Self::execute(order, &mut model, content) {
+                    OrderResult::Done => next_order += 1,
+                    OrderResult::Failed => {
+                        orders_failed += 1;
+                        next_order += 1;
+                    }
+                    OrderResult::Pending => break,
+                }
+            }
+            if elapsed >= next_sample {
+                gold_curve.push((elapsed.as_secs_f32(), model.wallet().balance()));
+                next_sample += sample_interval;
+            }
+            model.update(SIMULATION_STEP);
+            model.drain_events();
+            elapsed += SIMULATION_STEP;
+        }
+        gold_curve.push((elapsed.as_secs_f32(), model.wallet().balance()));
+
+        Ok(RunReport::new(
+            config.seed,
+            &model,
+            orders_failed,
+            gold_curve,
+        ))
+    }
+
+    fn execute(order: &Order, model: &mut Game, content: Content) -> OrderResult {
+        let result = match order {
+            Order::Build {
+                tower,
+                x,
+                y,
+                targeting,
+                ..
+            } => {
+                let Some(type_info) = content.tower(tower) else {
+                    return OrderResult::Failed;
+                };
+                let mut tower = Tower::new(Point { x: *x, y: *y }, type_info);
+                tower.set_targeting(*targeting);
+                model.spawn_tower(tower)
+            }
+            Order::Upgrade { x, y, .. } => {
+                let Some(idx) = model.tower_at(Point { x: *x, y: *y }) else {
+                    return OrderResult::Failed;
+                };
+                model.select_tower(Some(idx));
+                let result = model.upgrade_selected_tower();
+                model.select_tower(None);
+                result
+            }
+        };
+        match result {
+            Ok(()) => OrderResult::Done,
+            Err(err) if err.is::<NotEnoughMoneyErr>() => OrderResult::Pending,
+            Err(_) => OrderResult::Failed,
+        }
+    }
+}
+
+/// Times are clamped to be non-negative, but must fit into a Duration
+fn check_time(field: &'static str, secs: f32) -> Result<(), ScriptErr> {
+    if !secs.is_finite() || Duration::try_from_secs_f32(secs.max(0.0)).is_err() {
+        return Err(ScriptErr::InvalidTime(field, secs));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunReport {
+    seed: u64,
+    outcome: Option<GameOutcome>,
+    waves_survived: Option<usize>,
+    time_survived: f32,
+    enemies_killed: u64,
+    enemies_leaked: u64,
+    lives_lost: u64,
+    lives_remaining: u64,
+    towers_built: u64,
+    orders_failed: u64,
+    money_earned: u64,
+    money_spent: u64,
+    money_refunded: u64,
+    final_balance: u64,
+    /// Balance sampled over time, as (seconds, balance) pairs
+    gold_curve: Vec<(f32, u64)>,
+    damage_by_tower: BTreeMap<&'static str, f32>,
+}
+
+impl RunReport {
+    fn new(seed: u64, model: &Game, orders_failed: u64, gold_curve: Vec<(f32, u64)>) -> Self {
+        let stats = model.stats();
+        Self {
+            seed,
+            outcome: model.outcome(),
+            waves_survived: model.wave_status().map(|status| status.waves_cleared),
+            time_survived: stats.time_survived().as_secs_f32(),
+            enemies_killed: stats.total_enemies_killed(),
+            enemies_leaked: stats.enemies_leaked(),
+            lives_lost: stats.lives_lost(),
+            lives_remaining: model.lives().remaining(),
+            towers_built: stats.towers_built(),
+            orders_failed,
+            money_earned: stats.money_earned(),
+            money_spent: stats.money_spent(),
+            money_refunded: stats.money_refunded(),
+            final_balance: model.wallet().balance(),
+            gold_curve,
+            damage_by_tower: stats.damage_dealt().clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    /// One row per run, the gold curve is written as `seconds:balance` pairs
+    Csv,
+}
+
+pub fn write_reports(
+    reports: &[RunReport],
+    format: ReportFormat,
+    content: Content,
+    mut out: impl io::Write,
+) -> io::Result<()> {
+    match format {
+        ReportFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, reports)?;
+            writeln!(out)
+        }
+        ReportFormat::Csv => write_csv(reports, content, out),
+    }
+}
+
+fn write_csv(reports: &[RunReport], content: Content, mut out: impl io::Write) -> io::Result<()> {
+    let mut header = vec![
+        "seed",
+        "outcome",
+        "waves_survived",
+        "time_survived",
+        "enemies_killed",
+        "enemies_leaked",
+        "lives_lost",
+        "lives_remaining",
+        "towers_built",
+        "orders_failed",
+        "money_earned",
+        "money_spent",
+        "money_refunded",
+        "final_balance",
+        "gold_curve",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect::<Vec<_>>();
+    header.extend(
+        content
+            .towers
+            .iter()
+            .map(|tower| csv_field(&format!("damage {}", tower.name))),
+    );
+    writeln!(out, "{}", header.join(","))?;
+
+    for report in reports {
+        let outcome = report
+            .outcome
+            .map(|outcome| format!("{:?}", outcome))
+            .unwrap_or_default();
+        let waves_survived = report
+            .waves_survived
+            .map(|waves| waves.to_string())
+            .unwrap_or_default();
+        let gold_curve = report
+            .gold_curve
+            .iter()
+            .map(|(time, balance)| format!("{}:{}", time, balance))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut row = vec![
+            report.seed.to_string(),
+            outcome,
+            waves_survived,
+            report.time_survived.to_string(),
+            report.enemies_killed.to_string(),
+            report.enemies_leaked.to_string(),
+            report.lives_lost.to_string(),
+            report.lives_remaining.to_string(),
+            report.towers_built.to_string(),
+            report.orders_failed.to_string(),
+            report.money_earned.to_string(),
+            report.money_spent.to_string(),
+            report.money_refunded.to_string(),
+            report.final_balance.to_string(),
+            gold_curve,
+        ];
+        row.extend(content.towers.iter().map(|tower| {
+            let damage = report.damage_by_tower.get(tower.name).copied();
+            damage.unwrap_or_default().to_string()
+        }));
+        writeln!(out, "{}", row.join(","))?;
+    }
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -7,9 +7,12 @@ use ratatui::{
 
 use super::core::{Camera, Drawable};
 use crate::{
-    assets::tower_ui::{TOWER_GAP_DRAW_INFO, TOWER_RADIUS_DRAW_INFO},
+    assets::{
+        tower::EXPLOSION_DRAW_INFO,
+        tower_ui::{TOWER_GAP_DRAW_INFO, TOWER_RADIUS_DRAW_INFO},
+    },
     input::{TowerGap, TowerRadius},
-    model::Positioned,
+    model::{Explosion, Positioned, Tower},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -23,14 +26,24 @@ pub trait HasCircleDrawInfo: Positioned {
     fn radius(&self) -> f32;
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub struct CircleDrawable<'a, T: HasCircleDrawInfo> {
     instance: &'a T,
+    draw_info: &'static CircleDrawInfo,
 }
 
 impl<'a, T: HasCircleDrawInfo> CircleDrawable<'a, T> {
     pub fn new(instance: &'a T) -> Self {
-        Self { instance: instance }
+        Self {
+            instance,
+            draw_info: instance.draw_info(),
+        }
+    }
+
+    /// Overrides the look of the instance, e.g. to highlight it
+    pub fn set_draw_info(&mut self, draw_info: &'static CircleDrawInfo) -> &mut Self {
+        self.draw_info = draw_info;
+        self
     }
 
     pub fn radius(&self) -> f32 {
@@ -43,7 +56,7 @@ impl<'a, T: HasCircleDrawInfo> Drawable for CircleDrawable<'a, T> {
         let frame_w = frame.size().width;
         let frame_h = frame.size().height;
         let self_pos = self.instance.position();
-        let draw_info = self.instance.draw_info();
+        let draw_info = self.draw_info;
 
         let self_as_widget = Canvas::default()
             .marker(draw_info.marker)
@@ -83,3 +96,23 @@ impl HasCircleDrawInfo for TowerRadius {
         &TOWER_RADIUS_DRAW_INFO
     }
 }
+
+impl HasCircleDrawInfo for Tower {
+    fn radius(&self) -> f32 {
+        self.range()
+    }
+
+    fn draw_info(&self) -> &'static CircleDrawInfo {
+        &TOWER_RADIUS_DRAW_INFO
+    }
+}
+
+impl HasCircleDrawInfo for Explosion {
+    fn radius(&self) -> f32 {
+        self.radius()
+    }
+
+    fn draw_info(&self) -> &'static CircleDrawInfo {
+        &EXPLOSION_DRAW_INFO
+    }
+}
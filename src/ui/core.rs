@@ -9,18 +9,26 @@ use ratatui::{
     prelude::{Constraint, CrosstermBackend, Direction, Layout, Rect},
     Frame, Terminal,
 };
+use serde::{Deserialize, Serialize};
 
 use super::{
     circle_drawable::CircleDrawable,
+    enemy_panel::EnemyPanel,
+    help::HelpOverlay,
     point_drawable::{EnemyPositioned, PointDrawable},
     road::RoadDrawable,
+    summary::GameSummary,
+    toast::Toasts,
+    tower_selector::TowerPanel,
+    wallet::WalletStatus,
 };
 use crate::{
-    input::InputContext,
-    model::{GameModel, Point, Positioned},
+    assets::tower_ui::INVALID_PLACEMENT_DRAW_INFO,
+    input::{InputContext, Keymap},
+    model::{Enemy, GameModel, Point, Positioned},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Camera {
     position: Point,
     critical_scale: f32,
@@ -30,9 +38,9 @@ pub struct Camera {
 impl Camera {
     pub fn new(position: Point, crotical_scale: f32, scale: f32) -> Self {
         Self {
-            position: position,
+            position,
             critical_scale: crotical_scale,
-            scale: scale,
+            scale,
         }
     }
 
@@ -48,6 +56,48 @@ impl Camera {
             .constraints([Constraint::Max(1), Constraint::Min(0)])
     }
 
+    pub fn hud_layout(&self) -> Layout {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(1, 3),
+            ])
+    }
+
+    /// Centered rectangle taking the given share of the area
+    pub fn popup_area(&self, area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+        let vertical = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage((100 - percent_y) / 2),
+                Constraint::Percentage(percent_y),
+                Constraint::Percentage((100 - percent_y) / 2),
+            ])
+            .split(area);
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage((100 - percent_x) / 2),
+                Constraint::Percentage(percent_x),
+                Constraint::Percentage((100 - percent_x) / 2),
+            ])
+            .split(vertical[1])[1]
+    }
+
+    /// Centered rectangle of the given size, clipped to the area
+    pub fn sized_popup_area(&self, area: Rect, width: u16, height: u16) -> Rect {
+        let width = width.min(area.width);
+        let height = height.min(area.height);
+        Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        }
+    }
+
     pub fn scale(&self) -> f32 {
         self.scale
     }
@@ -91,6 +141,7 @@ impl Positioned for Camera {
 
 pub struct Screen {
     terminal: Terminal<CrosstermBackend<Stdout>>,
+    paused: bool,
 }
 
 pub trait Drawable {
@@ -100,7 +151,16 @@ pub trait Drawable {
 impl Screen {
     pub fn new() -> io::Result<Self> {
         let terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
-        Ok(Self { terminal: terminal })
+        Ok(Self {
+            terminal,
+            paused: false,
+        })
+    }
+
+    /// Enemies under the cursor are only inspected while the game is paused
+    pub fn set_paused(&mut self, paused: bool) -> &mut Self {
+        self.paused = paused;
+        self
     }
 
     pub fn init(&mut self) -> io::Result<()> {
@@ -126,25 +186,95 @@ impl Screen {
         camera: &Camera,
         game_model: &impl GameModel,
         input_context: &InputContext,
+        keymap: &Keymap,
+        toasts: &Toasts,
+    ) -> io::Result<()> {
+        let paused = self.paused;
+        self.terminal.draw(|frame| {
+            Self::draw_impl(
+                frame,
+                camera,
+                game_model,
+                input_context,
+                keymap,
+                toasts,
+                paused,
+            )
+        })?;
+
+        Ok(())
+    }
+
+    /// Game frame with a replay status bar or a menu drawn over it
+    pub fn draw_frame_with(
+        &mut self,
+        camera: &Camera,
+        game_model: &impl GameModel,
+        input_context: &InputContext,
+        keymap: &Keymap,
+        toasts: &Toasts,
+        overlay: &impl Drawable,
     ) -> io::Result<()> {
-        self.terminal
-            .draw(|frame| Self::draw_impl(frame, camera, game_model, input_context))?;
+        let paused = self.paused;
+        self.terminal.draw(|frame| {
+            Self::draw_impl(
+                frame,
+                camera,
+                game_model,
+                input_context,
+                keymap,
+                toasts,
+                paused,
+            );
+            overlay.draw(frame, camera);
+        })?;
 
         Ok(())
     }
 
+    pub fn draw_summary(&mut self, camera: &Camera, game_model: &impl GameModel) -> io::Result<()> {
+        let Some(outcome) = game_model.outcome() else {
+            return Ok(());
+        };
+        let summary = GameSummary::new(
+            outcome,
+            game_model.stats(),
+            game_model.ledger(),
+            game_model.seed(),
+        );
+        self.terminal.draw(|frame| summary.draw(frame, camera))?;
+
+        Ok(())
+    }
+
+    /// Enemy closest to the cursor, if it's close enough to be pointed at
+    fn inspected_enemy(game_model: &impl GameModel, cursor: Point) -> Option<&Enemy> {
+        const INSPECT_RADIUS: f32 = 1.5;
+        game_model
+            .enemies()
+            .map(|(_, enemy)| {
+                let position = EnemyPositioned::new(enemy, game_model.trajectory()).position();
+                (enemy, position.distance(cursor))
+            })
+            .filter(|&(_, distance)| distance <= INSPECT_RADIUS)
+            .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+            .map(|(enemy, _)| enemy)
+    }
+
     fn draw_impl(
         frame: &mut Frame,
         camera: &Camera,
         game_model: &impl GameModel,
         input_context: &InputContext,
+        keymap: &Keymap,
+        toasts: &Toasts,
+        paused: bool,
     ) {
         let drawable = RoadDrawable::new(game_model.trajectory());
         drawable.draw(frame, camera);
 
-        for enemy in game_model.enemies() {
-            let borrowed = &enemy.borrow();
-            let positioned = EnemyPositioned::new(borrowed, game_model.trajectory());
+        for (_, enemy) in game_model.enemies() {
+            let positioned = EnemyPositioned::new(enemy, game_model.trajectory());
             let drawable = PointDrawable::new(&positioned);
             drawable.draw(frame, camera);
         }
@@ -159,10 +289,46 @@ impl Screen {
             drawable.draw(frame, camera);
         }
 
-        input_context.tower_selector().draw(frame, camera);
-        CircleDrawable::new(&input_context.tower_radius()).draw(frame, camera);
-        CircleDrawable::new(&input_context.tower_gap()).draw(frame, camera);
+        for explosion in game_model.explosions() {
+            let drawable = CircleDrawable::new(explosion);
+            drawable.draw(frame, camera);
+        }
 
-        game_model.wallet().draw(frame, camera)
+        match game_model.selected_tower() {
+            Some(tower) => {
+                TowerPanel::new(tower).draw(frame, camera);
+                CircleDrawable::new(tower).draw(frame, camera);
+            }
+            None if paused => match Self::inspected_enemy(game_model, input_context.cursor()) {
+                Some(enemy) => EnemyPanel::new(enemy).draw(frame, camera),
+                None => input_context.tower_selector().draw(frame, camera),
+            },
+            None => input_context.tower_selector().draw(frame, camera),
+        }
+        let tower_radius = input_context.tower_radius();
+        let tower_gap = input_context.tower_gap();
+        let mut radius_drawable = CircleDrawable::new(&tower_radius);
+        let mut gap_drawable = CircleDrawable::new(&tower_gap);
+        if game_model.check_placement(tower_gap.position()).is_err() {
+            radius_drawable.set_draw_info(&INVALID_PLACEMENT_DRAW_INFO);
+            gap_drawable.set_draw_info(&INVALID_PLACEMENT_DRAW_INFO);
+        }
+        radius_drawable.draw(frame, camera);
+        gap_drawable.draw(frame, camera);
+
+        let income = game_model
+            .ledger()
+            .income_per_minute(game_model.stats().time_survived());
+        WalletStatus::new(game_model.wallet(), income).draw(frame, camera);
+        game_model.lives().draw(frame, camera);
+        if let Some(wave_status) = game_model.wave_status() {
+            wave_status.draw(frame, camera);
+        }
+        toasts.draw(frame, camera);
+
+        if input_context.help_shown() {
+            let scroll = input_context.help_scroll();
+            HelpOverlay::new(keymap, game_model.content().enemies, scroll).draw(frame, camera);
+        }
     }
 }
@@ -0,0 +1,60 @@
+use ratatui::{
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Paragraph, Wrap},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::model::Enemy;
+
+/// Details of the enemy under the cursor
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyPanel<'a> {
+    enemy: &'a Enemy,
+}
+
+impl<'a> EnemyPanel<'a> {
+    pub fn new(enemy: &'a Enemy) -> Self {
+        Self { enemy }
+    }
+
+    fn effects(&self) -> String {
+        let status = self.enemy.status_effects();
+        let effects = [
+            (status.is_stunned(), "stunned"),
+            (status.is_slowed(), "slowed"),
+            (status.is_burning(), "burning"),
+        ]
+        .into_iter()
+        .filter_map(|(active, name)| active.then_some(name))
+        .collect::<Vec<_>>();
+        if effects.is_empty() {
+            return "none".to_string();
+        }
+        effects.join(", ")
+    }
+}
+
+impl<'a> Drawable for EnemyPanel<'a> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let info = self.enemy.type_info();
+
+        let text = vec![
+            Line::from(format!("Enemy: {}", info.name).dark_gray()),
+            Line::from(
+                format!("Health: {:.0}/{}", self.enemy.health(), info.max_health).dark_gray(),
+            ),
+            Line::from(format!("Speed: {}", info.speed).dark_gray()),
+            Line::from(format!("Reward: {}$", info.reward).dark_gray()),
+            Line::from(format!("Resistances: {}", info.resistances).dark_gray()),
+            Line::from(format!("Effects: {}", self.effects()).dark_gray()),
+        ];
+
+        let layout = camera.ui_layout().split(frame.size());
+
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), layout[1]);
+    }
+}
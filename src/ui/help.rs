@@ -0,0 +1,130 @@
+use ratatui::{
+    prelude::{Constraint, Direction, Layout, Rect},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::{
+    input::{Action, Keymap},
+    model::EnemyInfo,
+};
+
+/// Key bindings and enemy types, drawn over the canvas. Both are shown side
+/// by side on wide screens and one after another on narrow ones
+#[derive(Debug, Clone, Copy)]
+pub struct HelpOverlay<'a> {
+    keymap: &'a Keymap,
+    enemies: &'static [EnemyInfo],
+    scroll: u16,
+}
+
+impl<'a> HelpOverlay<'a> {
+    const TWO_COLUMNS_MIN_WIDTH: u16 = 120;
+
+    pub fn new(keymap: &'a Keymap, enemies: &'static [EnemyInfo], scroll: u16) -> Self {
+        Self {
+            keymap,
+            enemies,
+            scroll,
+        }
+    }
+
+    /// Lines the help can be scrolled by before its end is shown
+    pub fn max_scroll(&self, camera: &Camera, frame_size: Rect) -> u16 {
+        let (_, inner) = Self::areas(camera, frame_size);
+        self.columns(inner)
+            .into_iter()
+            .map(|(area, lines)| (lines.len() as u16).saturating_sub(area.height))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn areas(camera: &Camera, frame_size: Rect) -> (Rect, Rect) {
+        let area = camera.popup_area(frame_size, 90, 90);
+        let inner = Block::new().borders(Borders::ALL).inner(area);
+        (area, inner)
+    }
+
+    fn columns(&self, inner: Rect) -> Vec<(Rect, Vec<Line<'static>>)> {
+        if inner.width < Self::TWO_COLUMNS_MIN_WIDTH {
+            let mut text = self.controls();
+            text.push(Line::from(""));
+            text.extend(self.bestiary());
+            return vec![(inner, text)];
+        }
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(inner);
+        vec![(columns[0], self.controls()), (columns[1], self.bestiary())]
+    }
+
+    fn keys(&self, action: Action) -> String {
+        let keys = self.keymap.keys(action);
+        if keys.is_empty() {
+            return "unbound".to_string();
+        }
+        keys.iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn controls(&self) -> Vec<Line<'static>> {
+        let mut text = vec![Line::from("Controls".bold()), Line::from("")];
+        text.push(Line::from(vec![
+            Span::from(format!("{:<17} ", "mouse")).yellow(),
+            Span::from("Left click builds or selects, right click sells"),
+        ]));
+        text.extend(Action::ALL.iter().map(|&action| {
+            Line::from(vec![
+                Span::from(format!("{:<17} ", self.keys(action))).yellow(),
+                Span::from(action.description()),
+            ])
+        }));
+        text
+    }
+
+    fn bestiary(&self) -> Vec<Line<'static>> {
+        let mut text = vec![Line::from("Bestiary".bold()), Line::from("")];
+        for enemy in self.enemies {
+            text.extend([
+                Line::from(enemy.name.yellow()),
+                Line::from(
+                    format!(
+                        "  Health: {}, speed: {}, reward: {}$, leak: {} lives",
+                        enemy.max_health, enemy.speed, enemy.reward, enemy.leak_damage
+                    )
+                    .dark_gray(),
+                ),
+                Line::from(format!("  Resistances: {}", enemy.resistances).dark_gray()),
+            ]);
+        }
+        text
+    }
+}
+
+impl<'a> Drawable for HelpOverlay<'a> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let (area, inner) = Self::areas(camera, frame.size());
+        let title = format!(
+            "Help ({} to close, {}/{} to scroll)",
+            self.keys(Action::HelpSwitch),
+            self.keys(Action::HelpScrollUp),
+            self.keys(Action::HelpScrollDown)
+        );
+        let block = Block::new().borders(Borders::ALL).title(title);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        for (column, lines) in self.columns(inner) {
+            let scroll = self
+                .scroll
+                .min((lines.len() as u16).saturating_sub(column.height));
+            frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), column);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use ratatui::{
+    layout::Alignment,
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::menu::{GameSetup, Menu, Settings};
+
+impl<T: Copy + Display> Drawable for Menu<T> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        draw_rows(frame, camera, self, |_| None);
+    }
+}
+
+impl Drawable for GameSetup {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        draw_rows(frame, camera, self.rows(), |row| self.value(row));
+    }
+}
+
+impl Drawable for Settings {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        draw_rows(frame, camera, self.rows(), |row| self.value(row));
+    }
+}
+
+/// Rows with optional values in a centered box, arrows mark the selected row
+fn draw_rows<T: Copy + Display>(
+    frame: &mut Frame,
+    camera: &Camera,
+    menu: &Menu<T>,
+    value: impl Fn(T) -> Option<String>,
+) {
+    let mut text = vec![Line::from("")];
+    text.extend(menu.items().iter().enumerate().map(|(idx, &item)| {
+        let selected = idx == menu.selected_index();
+        match (value(item), selected) {
+            (Some(value), true) => Line::from(format!("{}: < {} >", item, value).yellow().bold()),
+            (Some(value), false) => Line::from(format!("{}: {}", item, value).gray()),
+            (None, true) => Line::from(format!("> {} <", item).yellow().bold()),
+            (None, false) => Line::from(item.to_string().gray()),
+        }
+    }));
+
+    const WIDTH: u16 = 48;
+    // Borders and an empty line above and below the rows
+    let height = text.len() as u16 + 3;
+    let area = camera.sized_popup_area(frame.size(), WIDTH, height);
+    let block = Block::new()
+        .borders(Borders::ALL)
+        .title(menu.title())
+        .title_alignment(Alignment::Center);
+    let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph.block(block), area);
+}
@@ -4,14 +4,35 @@ pub use circle_drawable::*;
 mod core;
 pub use core::*;
 
+mod enemy_panel;
+pub use enemy_panel::*;
+
+mod help;
+pub use help::*;
+
+mod menu;
+
+mod pause;
+pub use pause::*;
+
 mod point_drawable;
 pub use point_drawable::*;
 
+mod replay;
+pub use replay::*;
+
 mod road;
 pub use road::*;
 
+mod summary;
+pub use summary::*;
+
+mod toast;
+pub use toast::*;
+
 mod tower_selector;
 pub use tower_selector::*;
 
 mod wallet;
-pub use wallet::*;
+
+mod wave;
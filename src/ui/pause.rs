@@ -0,0 +1,86 @@
+use ratatui::{
+    layout::Alignment,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::input::{Action, Keymap};
+
+/// Dims everything drawn so far and draws the overlay on top
+#[derive(Debug, Clone, Copy)]
+pub struct Dimmed<'a, T: Drawable> {
+    overlay: &'a T,
+}
+
+impl<'a, T: Drawable> Dimmed<'a, T> {
+    pub fn new(overlay: &'a T) -> Self {
+        Self { overlay }
+    }
+}
+
+impl<'a, T: Drawable> Drawable for Dimmed<'a, T> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let dim = Block::new().style(Style::new().dim());
+        frame.render_widget(dim, frame.size());
+        self.overlay.draw(frame, camera);
+    }
+}
+
+/// Shown while the game is paused without the pause menu
+#[derive(Debug, Clone, Copy)]
+pub struct PausePanel<'a> {
+    keymap: &'a Keymap,
+    build_phase: bool,
+}
+
+impl<'a> PausePanel<'a> {
+    pub fn new(keymap: &'a Keymap, build_phase: bool) -> Self {
+        Self {
+            keymap,
+            build_phase,
+        }
+    }
+
+    fn key(&self, action: Action) -> String {
+        self.keymap
+            .keys(action)
+            .first()
+            .map_or_else(|| action.to_string(), |key| key.to_string())
+    }
+}
+
+impl<'a> Drawable for PausePanel<'a> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        const WIDTH: u16 = 32;
+        const HEIGHT: u16 = 4;
+
+        let phase = if self.build_phase {
+            "Build phase".green()
+        } else {
+            "Building disabled".dark_gray()
+        };
+        let text = vec![
+            Line::from(phase),
+            Line::from(
+                format!(
+                    "{}: resume, {}: menu",
+                    self.key(Action::GamePauseSwitch),
+                    self.key(Action::TowerDeselect)
+                )
+                .dark_gray(),
+            ),
+        ];
+
+        let area = camera.sized_popup_area(frame.size(), WIDTH, HEIGHT);
+        let block = Block::new()
+            .borders(Borders::ALL)
+            .title("PAUSED".bold())
+            .title_alignment(Alignment::Center);
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph.block(block), area);
+    }
+}
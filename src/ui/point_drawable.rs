@@ -7,7 +7,10 @@ use ratatui::{
 
 use super::core::{Camera, Drawable};
 use crate::{
-    assets::tower::PROJECTILE_DRAW_INFO,
+    assets::{
+        enemy::{BURNING_ENEMY_FG_COLOR, SLOWED_ENEMY_FG_COLOR, STUNNED_ENEMY_FG_COLOR},
+        tower::PROJECTILE_DRAW_INFO,
+    },
     model::{Enemy, Point, Positioned, Projectile, Tower, Trajectory},
 };
 
@@ -21,7 +24,7 @@ pub struct PointDrawInfo {
 }
 
 pub trait HasPointDrawInfo: Positioned {
-    fn draw_info(&self) -> &'static PointDrawInfo;
+    fn draw_info(&self) -> PointDrawInfo;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -31,7 +34,7 @@ pub struct PointDrawable<'a, T: HasPointDrawInfo> {
 
 impl<'a, T: HasPointDrawInfo> PointDrawable<'a, T> {
     pub fn new(instance: &'a T) -> Self {
-        Self { instance: instance }
+        Self { instance }
     }
 }
 
@@ -44,24 +47,19 @@ impl<'a, T: HasPointDrawInfo> Drawable for PointDrawable<'a, T> {
 
         let self_as_widget = Canvas::default()
             .marker(draw_info.marker)
-            .paint(|ctx| {
-                if draw_info.close_up_sprite.is_some() && camera.allows_more_detail() {
-                    ctx.print(
-                        self_pos.x as f64,
-                        self_pos.y as f64,
-                        draw_info
-                            .close_up_sprite
-                            .unwrap()
-                            .fg(draw_info.fg_color)
-                            .bg(draw_info.bg_color)
-                            .add_modifier(draw_info.modifiers),
-                    );
-                } else {
-                    ctx.draw(&Points {
-                        coords: &[(self_pos.x as f64, self_pos.y as f64)],
-                        color: draw_info.fg_color,
-                    })
-                }
+            .paint(|ctx| match draw_info.close_up_sprite {
+                Some(sprite) if camera.allows_more_detail() => ctx.print(
+                    self_pos.x as f64,
+                    self_pos.y as f64,
+                    sprite
+                        .fg(draw_info.fg_color)
+                        .bg(draw_info.bg_color)
+                        .add_modifier(draw_info.modifiers),
+                ),
+                _ => ctx.draw(&Points {
+                    coords: &[(self_pos.x as f64, self_pos.y as f64)],
+                    color: draw_info.fg_color,
+                }),
             })
             .x_bounds(camera.x_bounds(frame_w))
             .y_bounds(camera.y_bounds(frame_h));
@@ -78,10 +76,7 @@ pub struct EnemyPositioned<'a, 'b> {
 
 impl<'a, 'b> EnemyPositioned<'a, 'b> {
     pub fn new(enemy: &'a Enemy, trajectory: &'b dyn Trajectory) -> Self {
-        Self {
-            enemy: enemy,
-            trajectory: trajectory,
-        }
+        Self { enemy, trajectory }
     }
 }
 
@@ -92,19 +87,28 @@ impl<'a, 'b> Positioned for EnemyPositioned<'a, 'b> {
 }
 
 impl<'a, 'b> HasPointDrawInfo for EnemyPositioned<'a, 'b> {
-    fn draw_info(&self) -> &'static PointDrawInfo {
-        &self.enemy.type_info().draw_info
+    fn draw_info(&self) -> PointDrawInfo {
+        let mut draw_info = self.enemy.type_info().draw_info;
+        let effects = self.enemy.status_effects();
+        if effects.is_stunned() {
+            draw_info.fg_color = STUNNED_ENEMY_FG_COLOR;
+        } else if effects.is_burning() {
+            draw_info.fg_color = BURNING_ENEMY_FG_COLOR;
+        } else if effects.is_slowed() {
+            draw_info.fg_color = SLOWED_ENEMY_FG_COLOR;
+        }
+        draw_info
     }
 }
 
 impl HasPointDrawInfo for Tower {
-    fn draw_info(&self) -> &'static PointDrawInfo {
-        &self.type_info().draw_info
+    fn draw_info(&self) -> PointDrawInfo {
+        self.type_info().draw_info
     }
 }
 
 impl HasPointDrawInfo for Projectile {
-    fn draw_info(&self) -> &'static PointDrawInfo {
-        &PROJECTILE_DRAW_INFO
+    fn draw_info(&self) -> PointDrawInfo {
+        PROJECTILE_DRAW_INFO
     }
 }
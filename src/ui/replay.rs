@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Stylize,
+    widgets::{Block, Paragraph},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::input::{Action, Keymap};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayStatus<'a> {
+    keymap: &'a Keymap,
+    elapsed: Duration,
+    total: Duration,
+    speed: u32,
+    paused: bool,
+}
+
+impl<'a> ReplayStatus<'a> {
+    pub fn new(
+        keymap: &'a Keymap,
+        elapsed: Duration,
+        total: Duration,
+        speed: u32,
+        paused: bool,
+    ) -> Self {
+        Self {
+            keymap,
+            elapsed,
+            total,
+            speed,
+            paused,
+        }
+    }
+
+    fn key(&self, action: Action) -> String {
+        self.keymap
+            .keys(action)
+            .first()
+            .map_or_else(|| action.to_string(), |key| key.to_string())
+    }
+}
+
+impl<'a> Drawable for ReplayStatus<'a> {
+    fn draw(&self, frame: &mut Frame, _camera: &Camera) {
+        let state = if self.paused { "paused" } else { "playing" };
+        let text = format!(
+            "Replay {}s/{}s x{} ({state}) | {}: pause, {}/{}: speed, {}/{}: seek",
+            self.elapsed.as_secs(),
+            self.total.as_secs(),
+            self.speed,
+            self.key(Action::GamePauseSwitch),
+            self.key(Action::ReplaySlowDown),
+            self.key(Action::ReplaySpeedUp),
+            self.key(Action::ReplaySeekBackward),
+            self.key(Action::ReplaySeekForward),
+        );
+
+        let size = frame.size();
+        let area = Rect {
+            y: size.bottom().saturating_sub(1),
+            height: size.height.min(1),
+            ..size
+        };
+
+        let paragraph = Paragraph::new(text.dark_gray()).alignment(Alignment::Right);
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), area);
+    }
+}
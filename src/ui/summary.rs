@@ -0,0 +1,88 @@
+use ratatui::{
+    layout::Alignment,
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::model::{GameOutcome, GameStats, Ledger, TransactionKind};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameSummary<'a> {
+    outcome: GameOutcome,
+    stats: &'a GameStats,
+    ledger: &'a Ledger,
+    seed: u64,
+}
+
+impl<'a> GameSummary<'a> {
+    pub fn new(outcome: GameOutcome, stats: &'a GameStats, ledger: &'a Ledger, seed: u64) -> Self {
+        Self {
+            outcome,
+            stats,
+            ledger,
+            seed,
+        }
+    }
+
+    fn title(&self) -> Line<'static> {
+        match self.outcome {
+            GameOutcome::Victory => Line::from("Victory!".green().bold()),
+            GameOutcome::Defeat => Line::from("Defeat".red().bold()),
+            GameOutcome::Quit => Line::from("Game abandoned".yellow().bold()),
+        }
+    }
+}
+
+impl<'a> Drawable for GameSummary<'a> {
+    fn draw(&self, frame: &mut Frame, _: &Camera) {
+        let survived = self.stats.time_survived().as_secs();
+        let mut text = vec![
+            self.title(),
+            Line::from(""),
+            Line::from(format!(
+                "Time survived: {}m {}s",
+                survived / 60,
+                survived % 60
+            )),
+            Line::from(format!("Towers built: {}", self.stats.towers_built())),
+            Line::from(format!("Money earned: {}$", self.stats.money_earned())),
+        ];
+        text.extend(
+            [
+                ("Kill bounties", TransactionKind::KillBounty),
+                ("Wave bonuses", TransactionKind::WaveBonus),
+                ("Interest", TransactionKind::Interest),
+            ]
+            .into_iter()
+            .map(|(name, kind)| {
+                Line::from(format!("  {}: {}$", name, self.ledger.total(kind)).dark_gray())
+            }),
+        );
+        text.extend([
+            Line::from(format!("Money spent: {}$", self.stats.money_spent())),
+            Line::from(format!("Money refunded: {}$", self.stats.money_refunded())),
+            Line::from(format!("Enemies leaked: {}", self.stats.enemies_leaked())),
+            Line::from(format!(
+                "Enemies killed: {}",
+                self.stats.total_enemies_killed()
+            )),
+        ]);
+        text.extend(
+            self.stats
+                .enemies_killed()
+                .iter()
+                .map(|(name, count)| Line::from(format!("  {}: {}", name, count).dark_gray())),
+        );
+        text.push(Line::from(""));
+        text.push(Line::from("Press any key to continue".dark_gray()));
+
+        let paragraph = Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        let block = Block::new().borders(Borders::ALL).title("Summary");
+        frame.render_widget(paragraph.block(block), frame.size());
+    }
+}
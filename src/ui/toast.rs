@@ -0,0 +1,97 @@
+use std::{collections::VecDeque, time::Duration};
+
+use ratatui::{
+    layout::Alignment,
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Paragraph},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ToastKind {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Toast {
+    text: String,
+    kind: ToastKind,
+    remaining: Duration,
+}
+
+/// Short messages shown in the top right corner for a few seconds,
+/// only the newest ones are kept
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Toasts {
+    toasts: VecDeque<Toast>,
+}
+
+impl Toasts {
+    const LIFETIME: Duration = Duration::from_secs(3);
+    const MAX_SHOWN: usize = 5;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, kind: ToastKind, text: impl Into<String>) {
+        if self.toasts.len() >= Self::MAX_SHOWN {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            text: text.into(),
+            kind,
+            remaining: Self::LIFETIME,
+        });
+    }
+
+    pub fn info(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Info, text);
+    }
+
+    pub fn warning(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Warning, text);
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Error, text);
+    }
+
+    pub fn update(&mut self, elapsed: Duration) {
+        for toast in self.toasts.iter_mut() {
+            toast.remaining = toast.remaining.saturating_sub(elapsed);
+        }
+        self.toasts.retain(|toast| !toast.remaining.is_zero());
+    }
+
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+}
+
+impl Drawable for Toasts {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let text = self
+            .toasts
+            .iter()
+            .map(|toast| {
+                let text = toast.text.as_str();
+                Line::from(match toast.kind {
+                    ToastKind::Info => text.gray(),
+                    ToastKind::Warning => text.yellow(),
+                    ToastKind::Error => text.red(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let layout = camera.ui_layout().split(frame.size());
+
+        let paragraph = Paragraph::new(text).alignment(Alignment::Right);
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), layout[1]);
+    }
+}
@@ -6,7 +6,7 @@ use ratatui::{
 };
 
 use super::core::{Camera, Drawable};
-use crate::input::TowerSelector;
+use crate::{input::TowerSelector, model::Tower};
 
 impl Drawable for TowerSelector {
     fn draw(&self, frame: &mut Frame, camera: &Camera) {
@@ -28,3 +28,63 @@ impl Drawable for TowerSelector {
         frame.render_widget(paragraph.block(block), layout[1]);
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct TowerPanel<'a> {
+    tower: &'a Tower,
+}
+
+impl<'a> TowerPanel<'a> {
+    pub fn new(tower: &'a Tower) -> Self {
+        Self { tower }
+    }
+}
+
+impl<'a> Drawable for TowerPanel<'a> {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let tower = self.tower;
+        let stats = tower.type_info();
+        let damage = tower.projectile_info().damage;
+
+        let text = match tower.next_upgrade() {
+            Some(next) => vec![
+                Line::from(
+                    format!(
+                        "Type: {} (level {}/{})",
+                        stats.name,
+                        tower.display_level(),
+                        tower.max_display_level()
+                    )
+                    .dark_gray(),
+                ),
+                Line::from(format!("Upgrade cost: {}$", next.cost).dark_gray()),
+                Line::from(
+                    format!("Damage: {} -> {}", damage, next.projectile_info.damage).dark_gray(),
+                ),
+                Line::from(
+                    format!(
+                        "Cooldown: {}ms -> {}ms",
+                        tower.cooldown().as_millis(),
+                        next.cooldown.as_millis()
+                    )
+                    .dark_gray(),
+                ),
+                Line::from(format!("Range: {} -> {}", tower.range(), next.range).dark_gray()),
+                Line::from(format!("Targeting: {}", tower.targeting()).dark_gray()),
+            ],
+            None => vec![
+                Line::from(format!("Type: {} (max level)", stats.name).dark_gray()),
+                Line::from(format!("Damage: {}", damage).dark_gray()),
+                Line::from(format!("Cooldown: {}ms", tower.cooldown().as_millis()).dark_gray()),
+                Line::from(format!("Range: {}", tower.range()).dark_gray()),
+                Line::from(format!("Targeting: {}", tower.targeting()).dark_gray()),
+            ],
+        };
+
+        let layout = camera.ui_layout().split(frame.size());
+
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), layout[1]);
+    }
+}
@@ -5,15 +5,47 @@ use ratatui::{
 };
 
 use super::core::{Camera, Drawable};
-use crate::model::Wallet;
+use crate::model::{Lives, Wallet};
 
-impl Drawable for Wallet {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WalletStatus {
+    wallet: Wallet,
+    income_per_minute: u64,
+}
+
+impl WalletStatus {
+    pub fn new(wallet: Wallet, income_per_minute: u64) -> Self {
+        Self {
+            wallet,
+            income_per_minute,
+        }
+    }
+}
+
+impl Drawable for WalletStatus {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let text = format!(
+            "Balance: {}$ (+{}$/min)",
+            self.wallet.balance(),
+            self.income_per_minute
+        );
+        let layout = camera.ui_layout().split(frame.size());
+        let hud = camera.hud_layout().split(layout[0]);
+
+        let paragraph = Paragraph::new(text.dark_gray()).wrap(Wrap { trim: true });
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), hud[0]);
+    }
+}
+
+impl Drawable for Lives {
     fn draw(&self, frame: &mut Frame, camera: &Camera) {
-        let text = format!("Balance: {}$", self.balance());
+        let text = format!("Lives: {}", self.remaining());
         let layout = camera.ui_layout().split(frame.size());
+        let hud = camera.hud_layout().split(layout[0]);
 
         let paragraph = Paragraph::new(text.dark_gray()).wrap(Wrap { trim: true });
         let block = Block::new();
-        frame.render_widget(paragraph.block(block), layout[0]);
+        frame.render_widget(paragraph.block(block), hud[1]);
     }
 }
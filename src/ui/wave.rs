@@ -0,0 +1,26 @@
+use ratatui::{
+    layout::Alignment,
+    style::Stylize,
+    widgets::{Block, Paragraph, Wrap},
+    Frame,
+};
+
+use super::core::{Camera, Drawable};
+use crate::model::WaveStatus;
+
+impl Drawable for WaveStatus {
+    fn draw(&self, frame: &mut Frame, camera: &Camera) {
+        let mut text = format!("Wave: {}/{}", self.wave, self.total_waves);
+        if let Some(until_next_wave) = self.until_next_wave {
+            text += &format!(" (next in {}s)", until_next_wave.as_secs());
+        }
+        let layout = camera.ui_layout().split(frame.size());
+        let hud = camera.hud_layout().split(layout[0]);
+
+        let paragraph = Paragraph::new(text.dark_gray())
+            .alignment(Alignment::Right)
+            .wrap(Wrap { trim: true });
+        let block = Block::new();
+        frame.render_widget(paragraph.block(block), hud[2]);
+    }
+}